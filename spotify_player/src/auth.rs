@@ -9,6 +9,13 @@ use librespot_core::{
 
 use crate::config::AppConfig;
 
+/// the backoff duration before the first reconnect attempt, which is doubled after each attempt
+#[cfg(feature = "streaming")]
+const INITIAL_RECONNECT_BACKOFF: std::time::Duration = std::time::Duration::from_secs(1);
+/// the maximum backoff duration between two reconnect attempts
+#[cfg(feature = "streaming")]
+const MAX_RECONNECT_BACKOFF: std::time::Duration = std::time::Duration::from_secs(60);
+
 fn read_user_auth_details(user: Option<String>) -> Result<(String, String)> {
     let mut username = String::new();
     let mut stdout = std::io::stdout();
@@ -58,25 +65,43 @@ async fn new_session_with_new_creds(cache: &Cache, app_config: &AppConfig) -> Re
     Err(anyhow!("authentication failed!"))
 }
 
-/// creates new Librespot session
-pub async fn new_session(
+/// gets the folder storing cached audio files
+pub fn audio_cache_folder(cache_folder: &std::path::Path) -> std::path::PathBuf {
+    cache_folder.join("audio")
+}
+
+fn new_cache(
     cache_folder: &std::path::Path,
     audio_cache: bool,
     app_config: &AppConfig,
-) -> Result<Session> {
+) -> Result<Cache> {
     // specifying `audio_cache` to `None` to disable audio cache
     let audio_cache_folder = if audio_cache {
-        Some(cache_folder.join("audio"))
+        Some(audio_cache_folder(cache_folder))
     } else {
         None
     };
+    // the least recently used audio files are removed when the cache exceeds the size limit
+    let size_limit = app_config
+        .device
+        .audio_cache_size_limit_in_mb
+        .map(|limit| limit * 1024 * 1024);
 
-    let cache = Cache::new(
+    Ok(Cache::new(
         Some(cache_folder),
         None,
         audio_cache_folder.as_deref(),
-        None,
-    )?;
+        size_limit,
+    )?)
+}
+
+/// creates new Librespot session
+pub async fn new_session(
+    cache_folder: &std::path::Path,
+    audio_cache: bool,
+    app_config: &AppConfig,
+) -> Result<Session> {
+    let cache = new_cache(cache_folder, audio_cache, app_config)?;
 
     // create a new session if either
     // - there is no cached credentials or
@@ -110,3 +135,45 @@ pub async fn new_session(
         }
     }
 }
+
+/// creates a new Librespot session using the cached credentials.
+///
+/// Unlike `new_session`, the function never prompts for new credentials. A failed connection
+/// is retried with an exponential backoff until the connection succeeds or the cached credentials
+/// are rejected.
+#[cfg(feature = "streaming")]
+pub async fn reconnect_session(
+    cache_folder: &std::path::Path,
+    audio_cache: bool,
+    app_config: &AppConfig,
+) -> Result<Session> {
+    let cache = new_cache(cache_folder, audio_cache, app_config)?;
+    let mut backoff = INITIAL_RECONNECT_BACKOFF;
+
+    loop {
+        let creds = match cache.credentials() {
+            None => return Err(anyhow!("no cached credentials found")),
+            Some(creds) => creds,
+        };
+        match Session::connect(
+            app_config.session_config(),
+            creds,
+            Some(cache.clone()),
+            true,
+        )
+        .await
+        {
+            Ok((session, _)) => return Ok(session),
+            Err(SessionError::AuthenticationError(err)) => {
+                return Err(anyhow!("failed to authenticate: {err:#}"));
+            }
+            Err(SessionError::IoError(err)) => {
+                tracing::warn!(
+                    "Failed to reconnect the session: {err:#}, retrying in {backoff:?}..."
+                );
+                tokio::time::sleep(backoff).await;
+                backoff = std::cmp::min(backoff * 2, MAX_RECONNECT_BACKOFF);
+            }
+        }
+    }
+}
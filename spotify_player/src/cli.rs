@@ -0,0 +1,536 @@
+use crate::{
+    auth, client,
+    config::AppConfig,
+    event::ClientRequest,
+    socket::{self, Request},
+    state::*,
+};
+use anyhow::{Context as _, Result};
+use clap::{Arg, ArgMatches, Command};
+#[cfg(unix)]
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines},
+    net::{
+        unix::{OwnedReadHalf, OwnedWriteHalf},
+        UnixStream,
+    },
+};
+
+/// initializes the application's CLI subcommands, which are used to
+/// interact with a running application instance
+pub fn init_cli_subcommands() -> Vec<Command<'static>> {
+    vec![
+        Command::new("playback")
+            .about("Control the current playback")
+            .subcommand_required(true)
+            .subcommand(Command::new("play").about("Resume the playback"))
+            .subcommand(Command::new("pause").about("Pause the playback"))
+            .subcommand(Command::new("toggle").about("Resume/pause the playback"))
+            .subcommand(Command::new("next").about("Next track"))
+            .subcommand(Command::new("previous").about("Previous track"))
+            .subcommand(Command::new("repeat").about("Cycle the repeat mode"))
+            .subcommand(Command::new("shuffle").about("Toggle the shuffle mode"))
+            .subcommand(
+                Command::new("volume")
+                    .about("Set the playback's volume")
+                    .arg(
+                        Arg::new("percent")
+                            .value_name("PERCENT")
+                            .required(true)
+                            .help("Volume percentage (0-100)"),
+                    ),
+            )
+            .subcommand(
+                Command::new("seek")
+                    .about("Seek to a position of the current track")
+                    .arg(
+                        Arg::new("position")
+                            .value_name("POSITION")
+                            .required(true)
+                            .help("Position in seconds or in the \"{minutes}:{seconds}\" format"),
+                    ),
+            )
+            .subcommand(
+                Command::new("queue")
+                    .about("Add a track to the playback's queue")
+                    .arg(
+                        Arg::new("uri")
+                            .value_name("URI")
+                            .required(true)
+                            .help("Spotify URI of the track"),
+                    ),
+            ),
+        Command::new("open")
+            .about("Open a Spotify URL or URI in a running application instance")
+            .arg(
+                Arg::new("uri")
+                    .value_name("URI")
+                    .required(true)
+                    .help("Spotify URL (https://open.spotify.com/...) or URI (spotify:...)"),
+            ),
+        Command::new("get")
+            .about("Get information from the application")
+            .subcommand_required(true)
+            .subcommand(
+                Command::new("playback")
+                    .about("Get the current playback")
+                    .arg(json_arg())
+                    .arg(
+                        Arg::new("format")
+                            .short('f')
+                            .long("format")
+                            .value_name("FORMAT")
+                            .help("Format template of the output (default: the `playback_format` config option)")
+                            .next_line_help(true),
+                    )
+                    .arg(
+                        Arg::new("follow")
+                            .long("follow")
+                            .help("Keep running and print a new output on every playback change"),
+                    ),
+            )
+            .subcommand(
+                Command::new("cache")
+                    .about("Get the audio cache's usage")
+                    .arg(json_arg()),
+            ),
+        Command::new("search")
+            .about("Search for tracks, artists, albums and playlists")
+            .arg(
+                Arg::new("query")
+                    .value_name("QUERY")
+                    .required(true)
+                    .help("Search query"),
+            )
+            .arg(json_arg()),
+    ]
+}
+
+fn json_arg() -> Arg<'static> {
+    Arg::new("json")
+        .long("json")
+        .help("Print the output in the JSON format")
+}
+
+/// handles a CLI subcommand by sending the corresponding request to a running application instance.
+/// If no running instance is found, the request is handled by a short-lived API client.
+pub async fn handle_cli_subcommand(
+    cmd: &str,
+    args: &ArgMatches,
+    config_folder: &std::path::Path,
+    cache_folder: &std::path::Path,
+) -> Result<()> {
+    let state = {
+        let mut state = State::default();
+        state.parse_config_files(config_folder, None)?;
+        std::sync::Arc::new(state)
+    };
+
+    let request = match cmd {
+        "playback" => match args.subcommand() {
+            Some(("play", _)) => Request::Play,
+            Some(("pause", _)) => Request::Pause,
+            Some(("toggle", _)) => Request::ResumePause,
+            Some(("next", _)) => Request::Next,
+            Some(("previous", _)) => Request::Previous,
+            Some(("repeat", _)) => Request::Repeat,
+            Some(("shuffle", _)) => Request::Shuffle,
+            Some(("volume", args)) => {
+                let percent = args.value_of("percent").unwrap();
+                Request::Volume {
+                    percent: percent
+                        .parse()
+                        .with_context(|| format!("invalid volume percentage {percent}"))?,
+                }
+            }
+            Some(("seek", args)) => Request::Seek {
+                position_ms: parse_position(args.value_of("position").unwrap())?,
+            },
+            Some(("queue", args)) => Request::Queue {
+                uri: args.value_of("uri").unwrap().to_string(),
+            },
+            _ => unreachable!("a subcommand is required"),
+        },
+        "get" => match args.subcommand() {
+            Some(("playback", args)) => {
+                return get_playback(args, &state, cache_folder).await;
+            }
+            Some(("cache", args)) => {
+                return get_cache(args, &state.app_config, cache_folder);
+            }
+            _ => unreachable!("a subcommand is required"),
+        },
+        "open" => {
+            let uri = args.value_of("uri").unwrap().to_string();
+            let link = crate::utils::parse_spotify_link(&uri)?;
+            let mut connection = Connection::new(&state, cache_folder).await?;
+            // only a track can be opened without a running application instance,
+            // opening other items requires the instance's UI to browse the items' pages
+            if !matches!(link, SpotifyLink::Track(_))
+                && matches!(connection, Connection::Client { .. })
+            {
+                anyhow::bail!("opening {uri} requires a running application instance");
+            }
+            connection.send(Request::Open { uri }).await?;
+            return Ok(());
+        }
+        "search" => Request::Search {
+            query: args.value_of("query").unwrap().to_string(),
+        },
+        _ => anyhow::bail!("unknown subcommand {cmd}"),
+    };
+
+    let data = Connection::new(&state, cache_folder)
+        .await?
+        .send(request)
+        .await?;
+    // only the `search` subcommand's request returns data
+    if let Some(data) = data {
+        if args.is_present("json") {
+            println!("{}", serde_json::to_string_pretty(&data)?);
+        } else {
+            print_search_results(serde_json::from_value(data)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// handles the `get playback` subcommand, which prints the current playback
+/// using a format template. In follow mode, a new output is printed on every playback change.
+async fn get_playback(
+    args: &ArgMatches,
+    state: &SharedState,
+    cache_folder: &std::path::Path,
+) -> Result<()> {
+    let is_json = args.is_present("json");
+    let is_follow = args.is_present("follow");
+    let format = args
+        .value_of("format")
+        .unwrap_or(&state.app_config.playback_format);
+
+    let mut connection = Connection::new(state, cache_folder).await?;
+    // In follow mode, a running application instance returns its cached playback
+    // to avoid making a Spotify API request every second.
+    // A short-lived API client has no cached playback, so it always makes the request.
+    #[cfg(unix)]
+    let cached = is_follow && matches!(connection, Connection::Socket { .. });
+    #[cfg(not(unix))]
+    let cached = false;
+    let mut last_output = None;
+    loop {
+        let data = connection
+            .send(Request::GetPlayback { cached })
+            .await?
+            .unwrap_or_default();
+        let output = if is_json {
+            if is_follow {
+                // each playback is printed in a single line in follow mode
+                serde_json::to_string(&data)?
+            } else {
+                serde_json::to_string_pretty(&data)?
+            }
+        } else {
+            format_playback(format, &serde_json::from_value(data)?, &state.app_config)
+        };
+
+        if !is_follow {
+            println!("{output}");
+            return Ok(());
+        }
+        if last_output.as_ref() != Some(&output) {
+            println!("{output}");
+            last_output = Some(output);
+        }
+
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    }
+}
+
+/// handles the `get cache` subcommand, which prints the audio cache's usage
+fn get_cache(
+    args: &ArgMatches,
+    app_config: &AppConfig,
+    cache_folder: &std::path::Path,
+) -> Result<()> {
+    /// gets the number of files and the total size of files in a folder, recursively
+    fn folder_usage(path: &std::path::Path) -> Result<(u64, u64)> {
+        let (mut files, mut size) = (0, 0);
+        for entry in std::fs::read_dir(path)? {
+            let entry = entry?;
+            let metadata = entry.metadata()?;
+            if metadata.is_dir() {
+                let (dir_files, dir_size) = folder_usage(&entry.path())?;
+                files += dir_files;
+                size += dir_size;
+            } else {
+                files += 1;
+                size += metadata.len();
+            }
+        }
+        Ok((files, size))
+    }
+
+    let folder = auth::audio_cache_folder(cache_folder);
+    let (files, size) = if folder.exists() {
+        folder_usage(&folder)
+            .with_context(|| format!("failed to read the audio cache folder {folder:?}"))?
+    } else {
+        (0, 0)
+    };
+    let limit = app_config.device.audio_cache_size_limit_in_mb;
+
+    if args.is_present("json") {
+        let data = serde_json::json!({
+            "enabled": app_config.device.audio_cache,
+            "folder": folder,
+            "files": files,
+            "size_in_bytes": size,
+            "size_limit_in_mb": limit,
+        });
+        println!("{}", serde_json::to_string_pretty(&data)?);
+    } else {
+        let enabled = if app_config.device.audio_cache {
+            "enabled"
+        } else {
+            "disabled"
+        };
+        let limit = match limit {
+            Some(limit) => format!("{limit} MB"),
+            None => "no limit".to_string(),
+        };
+        println!("Audio cache: {enabled}");
+        println!("Folder: {}", folder.display());
+        println!("Files: {files}");
+        println!("Size: {:.1} MB ({limit})", size as f64 / (1024.0 * 1024.0));
+    }
+
+    Ok(())
+}
+
+/// parses a track position in seconds or in the "{minutes}:{seconds}" format into milliseconds
+fn parse_position(position: &str) -> Result<u32> {
+    let secs = match position.split_once(':') {
+        Some((mins, secs)) => mins
+            .parse::<u32>()
+            .ok()
+            .zip(secs.parse::<u32>().ok().filter(|secs| *secs < 60))
+            .and_then(|(mins, secs)| mins.checked_mul(60)?.checked_add(secs)),
+        None => position.parse::<u32>().ok(),
+    };
+    match secs.and_then(|secs| secs.checked_mul(1000)) {
+        Some(position_ms) => Ok(position_ms),
+        None => anyhow::bail!("invalid position {position}"),
+    }
+}
+
+/// A connection to send requests to the application
+enum Connection {
+    /// a connection to a running application instance via the application's socket
+    #[cfg(unix)]
+    Socket {
+        reader: Lines<BufReader<OwnedReadHalf>>,
+        writer: OwnedWriteHalf,
+    },
+    /// a short-lived API client, which is used when there is no running application instance
+    Client {
+        state: SharedState,
+        client_pub: flume::Sender<ClientRequest>,
+    },
+}
+
+impl Connection {
+    /// connects to a running application instance or creates a short-lived API client
+    /// from the cached authentication credentials if no running instance is found
+    async fn new(state: &SharedState, cache_folder: &std::path::Path) -> Result<Self> {
+        #[cfg(unix)]
+        if let Ok(stream) = UnixStream::connect(cache_folder.join(socket::SOCKET_FILE)).await {
+            let (reader, writer) = stream.into_split();
+            return Ok(Self::Socket {
+                reader: BufReader::new(reader).lines(),
+                writer,
+            });
+        }
+
+        tracing::info!("No running application instance found, starting a short-lived client...");
+
+        let session = auth::new_session(cache_folder, false, &state.app_config).await?;
+        let client = client::Client::new(
+            session,
+            state.app_config.device.clone(),
+            state.app_config.client_id.clone(),
+            cache_folder.to_path_buf(),
+            // a short-lived client doesn't load the persisted data, so it shouldn't overwrite it
+            flume::unbounded().0,
+        );
+        client.init_token().await?;
+        client.update_current_playback_state(state).await?;
+
+        let (client_pub, client_sub) = flume::unbounded();
+        let (streaming_pub, streaming_sub) = flume::unbounded();
+        tokio::task::spawn({
+            let state = state.clone();
+            let client_pub = client_pub.clone();
+            async move {
+                client::start_client_handler(
+                    state,
+                    client,
+                    client_pub,
+                    client_sub,
+                    streaming_pub,
+                    streaming_sub,
+                )
+                .await;
+            }
+        });
+
+        Ok(Self::Client {
+            state: state.clone(),
+            client_pub,
+        })
+    }
+
+    /// sends a request and returns the request's data (if any)
+    async fn send(&mut self, request: Request) -> Result<Option<serde_json::Value>> {
+        match self {
+            #[cfg(unix)]
+            Self::Socket { reader, writer } => {
+                let mut request = serde_json::to_string(&request)?;
+                request.push('\n');
+                writer.write_all(request.as_bytes()).await?;
+
+                let response = reader
+                    .next_line()
+                    .await?
+                    .context("the running application instance didn't respond to the request")?;
+                let response: socket::Response = serde_json::from_str(&response)?;
+                if !response.ok {
+                    anyhow::bail!(response.error.unwrap_or_default());
+                }
+                Ok(response.data)
+            }
+            Self::Client { state, client_pub } => {
+                socket::handle_socket_request(request, state, client_pub).await
+            }
+        }
+    }
+}
+
+/// formats a playback into a string using a format template.
+/// Returns an empty string if there is no playback.
+fn format_playback(
+    format: &str,
+    playback: &Option<rspotify_model::CurrentPlaybackContext>,
+    app_config: &AppConfig,
+) -> String {
+    let playback = match playback {
+        None => return String::new(),
+        Some(playback) => playback,
+    };
+
+    // a podcast episode is formatted with its show's publisher and name
+    // in place of a track's artists and album
+    let (track, artists, album, duration) = match playback.item {
+        Some(rspotify_model::PlayableItem::Track(ref track)) => (
+            track.name.as_str(),
+            crate::utils::map_join(&track.artists, |a| &a.name, ", "),
+            track.album.name.as_str(),
+            crate::utils::format_duration(track.duration),
+        ),
+        Some(rspotify_model::PlayableItem::Episode(ref episode)) => (
+            episode.name.as_str(),
+            episode.show.publisher.clone(),
+            episode.show.name.as_str(),
+            crate::utils::format_duration(episode.duration),
+        ),
+        None => ("", String::new(), "", String::new()),
+    };
+
+    let status = if playback.is_playing {
+        &app_config.play_icon
+    } else {
+        &app_config.pause_icon
+    };
+    let progress = crate::utils::format_duration(playback.progress.unwrap_or_default());
+    let volume = playback
+        .device
+        .volume_percent
+        .unwrap_or_default()
+        .to_string();
+    let shuffle = playback.shuffle_state.to_string();
+    crate::utils::format_template(
+        format,
+        &[
+            ("status", status),
+            ("track", track),
+            ("artists", &artists),
+            ("album", album),
+            ("progress", &progress),
+            ("duration", &duration),
+            ("volume", &volume),
+            ("repeat", playback.repeat_state.as_ref()),
+            ("shuffle", &shuffle),
+            ("device", &playback.device.name),
+        ],
+    )
+}
+
+fn print_search_results(results: SearchResults) {
+    println!("Tracks:");
+    results
+        .tracks
+        .iter()
+        .for_each(|t| println!("  {t} ({})", t.id.uri()));
+    println!("Artists:");
+    results
+        .artists
+        .iter()
+        .for_each(|a| println!("  {a} ({})", a.id.uri()));
+    println!("Albums:");
+    results
+        .albums
+        .iter()
+        .for_each(|a| println!("  {a} ({})", a.id.uri()));
+    println!("Playlists:");
+    results
+        .playlists
+        .iter()
+        .for_each(|p| println!("  {p} ({})", p.id.uri()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_position;
+
+    #[test]
+    fn parse_position_in_seconds() {
+        assert_eq!(parse_position("0").unwrap(), 0);
+        assert_eq!(parse_position("80").unwrap(), 80_000);
+    }
+
+    #[test]
+    fn parse_position_in_minutes_and_seconds() {
+        assert_eq!(parse_position("1:20").unwrap(), 80_000);
+        assert_eq!(parse_position("0:05").unwrap(), 5_000);
+        assert_eq!(parse_position("10:00").unwrap(), 600_000);
+    }
+
+    #[test]
+    fn parse_invalid_position() {
+        for position in [
+            "", "-5", "1:80", "1:-5", ":", "1:", ":20", "1:2:3", "abc", "1.5",
+        ] {
+            assert!(
+                parse_position(position).is_err(),
+                "{position:?} should be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_out_of_range_position() {
+        assert!(parse_position("4294968").is_err());
+        assert!(parse_position("71583:00").is_err());
+        assert!(parse_position("99999999999").is_err());
+    }
+}
@@ -1,6 +1,6 @@
 use tracing::Instrument;
 
-use crate::{event::ClientRequest, state::*};
+use crate::{event::ClientRequest, hooks, state::*};
 
 /// starts the client's request handler
 pub async fn start_client_handler(
@@ -12,24 +12,37 @@ pub async fn start_client_handler(
     streaming_sub: flume::Receiver<()>,
 ) {
     while let Ok(request) = client_sub.recv_async().await {
+        // a request may come with a response channel to send back the request's handling result
+        let (request, response_pub) = match request {
+            ClientRequest::WithResponse(request, response_pub) => (*request, Some(response_pub)),
+            request => (request, None),
+        };
+
         match request {
             #[cfg(feature = "streaming")]
             ClientRequest::NewStreamingConnection => {
                 // send a notification to current streaming subcriber channels to shutdown all running connections
                 streaming_pub.send(()).unwrap_or_default();
-                match client
-                    .new_streaming_connection(streaming_sub.clone(), client_pub.clone())
+                let result = match client
+                    .new_streaming_connection(&state, streaming_sub.clone(), client_pub.clone())
                     .await
                 {
-                    Err(err) => tracing::error!(
-                        "Encountered an error during creating a new streaming connection: {err:#}",
-                    ),
+                    Err(err) => {
+                        tracing::error!(
+                            "Encountered an error during creating a new streaming connection: {err:#}",
+                        );
+                        Err(err)
+                    }
                     Ok(id) => {
                         // By default, when `NewStreamingConnection` is called, the app also connects to the new device
                         client_pub
                             .send(ClientRequest::ConnectDevice(Some(id)))
                             .unwrap_or_default();
+                        Ok(())
                     }
+                };
+                if let Some(response_pub) = response_pub {
+                    response_pub.send(result).unwrap_or_default();
                 }
             }
             _ => {
@@ -38,9 +51,13 @@ pub async fn start_client_handler(
                 let span = tracing::info_span!("client_request", request = ?request);
                 tokio::task::spawn(
                     async move {
-                        if let Err(err) = client.handle_request(&state, request).await {
+                        let result = client.handle_request(&state, request).await;
+                        if let Err(ref err) = result {
                             tracing::error!("Failed to handle client request: {err:#}");
                         }
+                        if let Some(response_pub) = response_pub {
+                            response_pub.send(result).unwrap_or_default();
+                        }
                     }
                     .instrument(span),
                 );
@@ -49,6 +66,94 @@ pub async fn start_client_handler(
     }
 }
 
+/// starts a data persister that persists the application's data into the cache folder
+/// upon receiving a notification from the client.
+/// Notifications received within a short delay are batched into a single write.
+pub async fn start_data_persister(
+    state: SharedState,
+    cache_folder: std::path::PathBuf,
+    persist_sub: flume::Receiver<()>,
+) {
+    let delay = std::time::Duration::from_secs(5);
+
+    while persist_sub.recv_async().await.is_ok() {
+        tokio::time::sleep(delay).await;
+        persist_sub.drain();
+
+        // the data is serialized while holding the lock and written to disk after releasing it
+        let data = state.data.read().serialize_persisted_data();
+        let result = match data {
+            Err(err) => Err(err),
+            Ok(data) => {
+                let cache_folder = cache_folder.clone();
+                tokio::task::spawn_blocking(move || write_persisted_data(&cache_folder, data))
+                    .await
+                    .unwrap_or_else(|err| Err(err.into()))
+            }
+        };
+        if let Err(err) = result {
+            tracing::warn!("Failed to persist the application's data: {err:#}");
+        }
+    }
+}
+
+/// starts a supervisor that reconnects the client's Librespot session when it's terminated,
+/// e.g. after a network failure or a system sleep/resume.
+///
+/// A new streaming connection is created after reconnecting, and the playback is transferred
+/// to the new integrated device if it was played on the old one.
+#[cfg(feature = "streaming")]
+pub async fn start_session_supervisor(
+    state: SharedState,
+    client: super::Client,
+    client_pub: flume::Sender<ClientRequest>,
+    streaming_sub: flume::Receiver<()>,
+) {
+    let check_interval = std::time::Duration::from_secs(1);
+
+    loop {
+        tokio::time::sleep(check_interval).await;
+        if !client.is_session_invalid() {
+            continue;
+        }
+
+        tracing::warn!("The Librespot session was terminated, reconnecting...");
+        // whether the last known playback was played on the integrated device
+        let was_integrated = state.player.read().is_playing_on_integrated_device();
+
+        if let Err(err) = client.reconnect_session(&state).await {
+            tracing::error!(
+                "Failed to reconnect the session: {err:#}. Please restart the application to re-authenticate."
+            );
+            return;
+        }
+        tracing::info!("Successfully reconnected the session");
+
+        match client
+            .new_streaming_connection(&state, streaming_sub.clone(), client_pub.clone())
+            .await
+        {
+            Err(err) => {
+                tracing::error!(
+                    "Encountered an error during creating a new streaming connection: {err:#}"
+                );
+            }
+            Ok(id) => {
+                if was_integrated {
+                    client_pub
+                        .send_async(ClientRequest::ConnectDevice(Some(id)))
+                        .await
+                        .unwrap_or_default();
+                }
+            }
+        }
+        client_pub
+            .send_async(ClientRequest::GetCurrentPlayback)
+            .await
+            .unwrap_or_default();
+    }
+}
+
 /// Starts multiple event watchers listening to events and
 /// notifying the client to make update requests if needed
 pub async fn start_player_event_watchers(
@@ -76,28 +181,87 @@ pub async fn start_player_event_watchers(
 
     let refresh_duration = std::time::Duration::from_millis(200);
 
+    // the last player snapshot, which is used to detect player events to run user-defined hooks
+    let mut last_snapshot: Option<hooks::PlayerSnapshot> = None;
+
+    // the recently played tracks (from the least recent to the most recent),
+    // which are used as seeds to get recommended tracks for autoplay
+    let mut recent_tracks: Vec<Track> = vec![];
+    // whether the current track has ended. If the playback stops right after
+    // a track ends, the playing context has finished
+    let mut is_track_ended = false;
+
     // Main watcher task
     loop {
         tokio::time::sleep(refresh_duration).await;
 
+        // run user-defined hooks of player events happened since the last snapshot
+        let snapshot = hooks::PlayerSnapshot::new(&state.player.read());
+        if let Some(snapshot) = snapshot {
+            let mut is_new_track = last_snapshot.is_none();
+            if let Some(ref last_snapshot) = last_snapshot {
+                for event in snapshot.events_since(last_snapshot) {
+                    hooks::run_hook(event, &snapshot, &state.app_config.hooks);
+                    is_new_track |= event == hooks::PlayerEvent::TrackChange;
+                }
+            }
+
+            // the playback's queue changes when moving to a new track, so it's refreshed
+            // for the queue page and for pre-fetching the queue's next tracks
+            if is_new_track
+                && (matches!(state.ui.lock().current_page(), PageState::Queue { .. })
+                    || super::should_prefetch_tracks(&state))
+            {
+                client_pub
+                    .send_async(ClientRequest::GetCurrentUserQueue)
+                    .await
+                    .unwrap_or_default();
+            }
+            last_snapshot = Some(snapshot);
+        }
+
+        if state.app_config.autoplay {
+            update_recent_tracks(&state, &mut recent_tracks);
+        }
+
         // update the playback when the current track ends
-        let (progress_ms, duration_ms, is_playing) = {
+        let (progress_ms, duration_ms, is_playing, has_playback, is_integrated) = {
             let player = state.player.read();
 
             (
                 player.playback_progress(),
-                player.current_playing_track().map(|t| t.duration),
+                player.current_playing_item_duration(),
                 player
                     .playback
                     .as_ref()
                     .map(|p| p.is_playing)
                     .unwrap_or_default(),
+                player.playback.is_some(),
+                player.is_playing_on_integrated_device(),
             )
         };
         if let (Some(progress_ms), Some(duration_ms)) = (progress_ms, duration_ms) {
             if progress_ms >= duration_ms && is_playing {
+                // the integrated player's events already trigger a playback update when the track ends
+                if !is_integrated {
+                    client_pub
+                        .send_async(ClientRequest::GetCurrentPlayback)
+                        .await
+                        .unwrap_or_default();
+                }
+                is_track_ended = true;
+            } else if is_playing {
+                is_track_ended = false;
+            }
+        }
+
+        // continue the finished context's playback with recommended tracks
+        if is_track_ended && has_playback && !is_playing {
+            is_track_ended = false;
+            if state.app_config.autoplay && !recent_tracks.is_empty() {
+                tracing::info!("The playing context has finished, start autoplaying...");
                 client_pub
-                    .send_async(ClientRequest::GetCurrentPlayback)
+                    .send_async(ClientRequest::Autoplay(recent_tracks.clone()))
                     .await
                     .unwrap_or_default();
             }
@@ -127,6 +291,7 @@ pub async fn start_player_event_watchers(
                             ContextId::Album(_) => ContextPageUIState::new_album(),
                             ContextId::Artist(_) => ContextPageUIState::new_artist(),
                             ContextId::Playlist(_) => ContextPageUIState::new_playlist(),
+                            ContextId::Show(_) => ContextPageUIState::new_show(),
                         });
                     }
                     None => {
@@ -144,5 +309,51 @@ pub async fn start_player_event_watchers(
                 }
             }
         }
+
+        // request audio features of the current context's tracks once the context is loaded
+        if state.app_config.track_table_audio_features {
+            let context_id = match state.ui.lock().current_page() {
+                PageState::Context { id: Some(id), .. } => Some(id.clone()),
+                _ => None,
+            };
+            if let Some(id) = context_id {
+                let uri = id.uri();
+                // the write lock is only acquired when the audio features need to be requested
+                let should_request = {
+                    let data = state.data.read();
+                    data.caches.context.contains(&uri)
+                        && !data.caches.incomplete_contexts.contains(&uri)
+                        && !data.caches.audio_features_contexts.contains(&uri)
+                };
+                if should_request {
+                    let mut data = state.data.write();
+                    if data.caches.audio_features_contexts.insert(uri.clone()) {
+                        data.caches.loading_audio_features.insert(uri, None);
+                        client_pub
+                            .send(ClientRequest::GetContextAudioFeatures(id))
+                            .unwrap_or_default();
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// adds the current playing track to the recently played tracks if it's a new track
+fn update_recent_tracks(state: &SharedState, recent_tracks: &mut Vec<Track>) {
+    let player = state.player.read();
+    let track = match player.current_playing_track() {
+        Some(track) => track,
+        None => return,
+    };
+    if track.id.is_none() || recent_tracks.last().map(|t| &t.id) == track.id.as_ref() {
+        return;
+    }
+
+    if let Some(track) = Track::try_from_full_track(track.clone()) {
+        recent_tracks.push(track);
+        if recent_tracks.len() > MAX_RECOMMENDATION_SEEDS {
+            recent_tracks.remove(0);
+        }
     }
 }
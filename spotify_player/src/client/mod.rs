@@ -19,19 +19,48 @@ mod spotify;
 
 pub use handlers::*;
 
+/// the maximum number of items in a request to add or remove playlist items
+const PLAYLIST_ITEMS_LIMIT: usize = 100;
+/// the maximum number of tracks in a request to check, save or remove liked tracks
+const SAVED_TRACKS_LIMIT: usize = 50;
+/// the maximum number of tracks in a request to get audio features
+const AUDIO_FEATURES_LIMIT: usize = 100;
+
 /// The application's client
 #[derive(Clone)]
 pub struct Client {
     spotify: Arc<spotify::Spotify>,
     http: reqwest::Client,
+    /// the application's cache folder, which is used to reconnect the Librespot session
+    #[cfg_attr(not(feature = "streaming"), allow(dead_code))]
+    cache_folder: std::path::PathBuf,
+    /// a channel to notify the data persister that the application's data needs to be persisted
+    persist_pub: flume::Sender<()>,
+    /// a lock to handle playlist reorder requests one by one, each request
+    /// depends on the playlist's snapshot returned by the previous request
+    playlist_reorder_lock: Arc<tokio::sync::Mutex<()>>,
+    /// the tracks whose audio files are being pre-fetched
+    #[cfg(feature = "streaming")]
+    prefetching_tracks: Arc<Mutex<std::collections::HashSet<TrackId>>>,
 }
 
 impl Client {
     /// creates a new client
-    pub fn new(session: Session, device: config::DeviceConfig, client_id: String) -> Self {
+    pub fn new(
+        session: Session,
+        device: config::DeviceConfig,
+        client_id: String,
+        cache_folder: std::path::PathBuf,
+        persist_pub: flume::Sender<()>,
+    ) -> Self {
         Self {
             spotify: Arc::new(spotify::Spotify::new(session, device, client_id)),
             http: reqwest::Client::new(),
+            cache_folder,
+            persist_pub,
+            playlist_reorder_lock: Arc::new(tokio::sync::Mutex::new(())),
+            #[cfg(feature = "streaming")]
+            prefetching_tracks: Arc::new(Mutex::new(std::collections::HashSet::new())),
         }
     }
 
@@ -39,22 +68,47 @@ impl Client {
     #[cfg(feature = "streaming")]
     pub async fn new_streaming_connection(
         &self,
+        state: &SharedState,
         streaming_sub: flume::Receiver<()>,
         client_pub: flume::Sender<ClientRequest>,
     ) -> Result<String> {
-        let session = match self.spotify.session {
+        let session = match self.spotify.session() {
             None => {
                 anyhow::bail!("No Spotify session found.");
             }
-            Some(ref session) => session.clone(),
+            Some(session) => session,
         };
         let device = self.spotify.device.clone();
         let device_id = session.device_id().to_string();
-        streaming::new_connection(session, device, client_pub, streaming_sub)?;
+        streaming::new_connection(session, device, state, client_pub, streaming_sub)?;
 
         Ok(device_id)
     }
 
+    /// checks if the client's Librespot session is terminated
+    #[cfg(feature = "streaming")]
+    pub fn is_session_invalid(&self) -> bool {
+        match self.spotify.session() {
+            Some(session) => session.is_invalid(),
+            None => false,
+        }
+    }
+
+    /// reconnects the client's Librespot session using the cached credentials
+    /// and retrieves a new authentication token with the new session
+    #[cfg(feature = "streaming")]
+    pub async fn reconnect_session(&self, state: &SharedState) -> Result<()> {
+        let session = crate::auth::reconnect_session(
+            &self.cache_folder,
+            state.app_config.device.audio_cache,
+            &state.app_config,
+        )
+        .await?;
+        *self.spotify.session.write() = Some(session);
+        self.spotify.refresh_token().await?;
+        Ok(())
+    }
+
     /// initializes the authentication token inside the Spotify client
     pub async fn init_token(&self) -> Result<()> {
         self.spotify.refresh_token().await?;
@@ -90,6 +144,16 @@ impl Client {
         match request {
             PlayerRequest::NextTrack => self.spotify.next_track(device_id).await?,
             PlayerRequest::PreviousTrack => self.spotify.previous_track(device_id).await?,
+            PlayerRequest::Resume => {
+                if !playback.is_playing {
+                    self.spotify.resume_playback(device_id, None).await?
+                }
+            }
+            PlayerRequest::Pause => {
+                if playback.is_playing {
+                    self.spotify.pause_playback(device_id).await?
+                }
+            }
             PlayerRequest::ResumePause => {
                 if !playback.is_playing {
                     self.spotify.resume_playback(device_id, None).await?
@@ -115,6 +179,11 @@ impl Client {
                     .await?
             }
             PlayerRequest::Volume(volume) => self.spotify.volume(volume, device_id).await?,
+            PlayerRequest::SkipTracks(n) => {
+                for _ in 0..n {
+                    self.spotify.next_track(device_id).await?;
+                }
+            }
             PlayerRequest::StartPlayback(p) => {
                 self.start_playback(p, device_id).await?;
                 // for some reasons, when starting a new playback, the integrated `spotify-player`
@@ -135,6 +204,29 @@ impl Client {
     pub async fn handle_request(&self, state: &SharedState, request: ClientRequest) -> Result<()> {
         let timer = std::time::SystemTime::now();
 
+        // requests updating the user data or the context caches need to persist the new data
+        let should_persist_data = matches!(
+            request,
+            ClientRequest::GetCurrentUser
+                | ClientRequest::GetUserPlaylists
+                | ClientRequest::GetUserFollowedArtists
+                | ClientRequest::GetUserSavedAlbums
+                | ClientRequest::GetUserSavedTracks
+                | ClientRequest::GetUserSavedShows
+                | ClientRequest::GetContext(_)
+                | ClientRequest::GetContextAudioFeatures(..)
+                | ClientRequest::AddTracksToPlaylist(..)
+                | ClientRequest::DeleteTracksFromPlaylist(..)
+                | ClientRequest::MovePlaylistTrack(..)
+                | ClientRequest::AddToLibrary(_)
+                | ClientRequest::DeleteFromLibrary(_)
+                | ClientRequest::AddTracksToLibrary(_)
+                | ClientRequest::DeleteTracksFromLibrary(_)
+                | ClientRequest::CreatePlaylist(..)
+                | ClientRequest::EditPlaylist(..)
+                | ClientRequest::AddToSearchHistory(_)
+        );
+
         match request {
             ClientRequest::ConnectDevice(id) => {
                 // Device connection can fail when the specified device hasn't shown up
@@ -192,6 +284,15 @@ impl Client {
                     .category_playlists
                     .insert(category.id, playlists);
             }
+            ClientRequest::GetUserPublicPlaylists(user_id) => {
+                let playlists = self.user_public_playlists(&user_id).await?;
+                state
+                    .data
+                    .write()
+                    .browse
+                    .user_playlists
+                    .insert(user_id.id().to_string(), playlists);
+            }
             #[cfg(feature = "lyric-finder")]
             ClientRequest::GetLyric { track, artists } => {
                 let client = lyric_finder::Client::from_http_client(&self.http);
@@ -210,19 +311,43 @@ impl Client {
             ClientRequest::NewStreamingConnection => {
                 anyhow::bail!("request should be already handled by the caller function");
             }
+            ClientRequest::WithResponse(..) => {
+                anyhow::bail!("request should be already handled by the caller function");
+            }
             ClientRequest::GetCurrentUser => {
                 let user = self.spotify.current_user().await?;
                 state.data.write().user_data.user = Some(user);
             }
             ClientRequest::Player(request) => {
+                // changes to the integrated device's playback state, position and volume
+                // are applied by the integrated player's events, so the playback isn't refreshed
+                let is_updated_by_events =
+                    matches!(
+                        request,
+                        PlayerRequest::Resume
+                            | PlayerRequest::Pause
+                            | PlayerRequest::ResumePause
+                            | PlayerRequest::SeekTrack(_)
+                            | PlayerRequest::Volume(_)
+                    ) && state.player.read().is_playing_on_integrated_device();
                 self.handle_player_request(state, request).await?;
-                self.update_playback(state);
+                if !is_updated_by_events {
+                    self.update_playback(state);
+                }
             }
             ClientRequest::GetCurrentPlayback => {
                 self.update_current_playback_state(state).await?;
                 #[cfg(feature = "image")]
                 self.get_current_track_cover_image(state).await?;
             }
+            ClientRequest::GetCurrentUserQueue => {
+                let queue = self.current_user_queue().await?;
+                state.player.write().queue = Some(queue);
+                #[cfg(feature = "streaming")]
+                if should_prefetch_tracks(state) {
+                    self.prefetch_next_tracks(state);
+                }
+            }
             ClientRequest::GetDevices => {
                 let devices = self.spotify.device().await?;
                 state.player.write().devices = devices
@@ -232,7 +357,25 @@ impl Client {
             }
             ClientRequest::GetUserPlaylists => {
                 let playlists = self.current_user_playlists().await?;
-                state.data.write().user_data.playlists = playlists;
+                let mut data = state.data.write();
+
+                // revalidate the cached playlist contexts using the playlists' snapshot IDs
+                for playlist in &playlists {
+                    let uri = playlist.id.uri();
+                    if let Some(Context::Playlist { playlist: p, .. }) =
+                        data.caches.context.peek(&uri)
+                    {
+                        if p.snapshot_id != playlist.snapshot_id {
+                            tracing::info!(
+                                "Playlist context ({uri}) is outdated, remove it from the cache"
+                            );
+                            data.caches.context.pop(&uri);
+                        }
+                        data.caches.persisted_contexts.remove(&uri);
+                    }
+                }
+
+                data.user_data.playlists = playlists;
             }
             ClientRequest::GetUserFollowedArtists => {
                 let artists = self.current_user_followed_artists().await?;
@@ -242,6 +385,10 @@ impl Client {
                 let albums = self.current_user_saved_albums().await?;
                 state.data.write().user_data.saved_albums = albums;
             }
+            ClientRequest::GetUserSavedShows => {
+                let shows = self.current_user_saved_shows().await?;
+                state.data.write().user_data.saved_shows = shows;
+            }
             ClientRequest::GetUserTopTracks => {
                 let id = "top-tracks";
                 if !state.data.read().caches.tracks.contains(id) {
@@ -250,8 +397,29 @@ impl Client {
                 }
             }
             ClientRequest::GetUserSavedTracks => {
-                let tracks = self.current_user_saved_tracks().await?;
-                state.data.write().user_data.saved_tracks = tracks;
+                let first_page = self
+                    .spotify
+                    .current_user_saved_tracks_manual(None, Some(50), None)
+                    .await?;
+
+                // The saved tracks (possibly loaded from the persisted cache) are up-to-date
+                // if the number of saved tracks and the tracks in the first page are unchanged.
+                let is_up_to_date = {
+                    let saved_tracks = &state.data.read().user_data.saved_tracks;
+                    saved_tracks.len() == first_page.total as usize
+                        && first_page
+                            .items
+                            .iter()
+                            .zip(saved_tracks.iter())
+                            .all(|(t, st)| {
+                                t.track.id.as_ref() == Some(&st.id)
+                                    && t.added_at.timestamp() as u64 == st.added_at
+                            })
+                };
+
+                if !is_up_to_date {
+                    self.current_user_saved_tracks(state, first_page).await?;
+                }
             }
             ClientRequest::GetUserRecentlyPlayedTracks => {
                 let id = "recently-played-tracks";
@@ -261,17 +429,26 @@ impl Client {
                 }
             }
             ClientRequest::GetContext(context) => {
-                let uri = context.uri();
-                if !state.data.read().caches.context.contains(&uri) {
-                    let context = match context {
-                        ContextId::Playlist(playlist_id) => {
-                            self.playlist_context(&playlist_id).await?
-                        }
-                        ContextId::Album(album_id) => self.album_context(&album_id).await?,
-                        ContextId::Artist(artist_id) => self.artist_context(&artist_id).await?,
-                    };
+                self.get_context(state, context).await?;
+            }
+            ClientRequest::GetContextAudioFeatures(context_id) => {
+                let uri = context_id.uri();
+                let result = self.context_audio_features(state, &uri).await;
+
+                let mut data = state.data.write();
+                let order = data.caches.loading_audio_features.remove(&uri).flatten();
+                if result.is_err() {
+                    // allow the audio features to be requested again
+                    data.caches.audio_features_contexts.remove(&uri);
+                    return result;
+                }
 
-                    state.data.write().caches.context.put(uri, context);
+                // sort the context's tracks by the order requested while loading the audio features
+                if let Some(order) = order {
+                    if let Some(context) = data.caches.context.peek_mut(&uri) {
+                        context.sort_tracks(order);
+                        data.caches.sorted_contexts.insert(uri);
+                    }
                 }
             }
             ClientRequest::Search(query) => {
@@ -281,33 +458,92 @@ impl Client {
                     state.data.write().caches.search.put(query, results);
                 }
             }
-            ClientRequest::GetRecommendations(seed) => {
-                let id = format!("recommendations::{}", seed.uri());
+            ClientRequest::SearchMore(query, search_type) => {
+                self.search_more(state, &query, search_type).await?;
+            }
+            ClientRequest::AddToSearchHistory(query) => {
+                state.data.write().user_data.add_search_history(query);
+            }
+            ClientRequest::GetRecommendations(query) => {
+                let id = query.id();
                 if !state.data.read().caches.tracks.contains(&id) {
-                    let tracks = self.recommendations(&seed).await?;
+                    let tracks = self.recommendations(&query).await?;
 
                     state.data.write().caches.tracks.put(id, tracks);
                 }
             }
-            ClientRequest::AddTrackToQueue(track_id) => {
-                self.add_track_to_queue(&track_id).await?;
+            ClientRequest::Autoplay(tracks) => {
+                self.autoplay(state, tracks).await?;
+            }
+            ClientRequest::AddTracksToQueue(track_ids) => {
+                self.add_tracks_to_queue(&track_ids).await?;
+                // refresh the queue shown in the queue page
+                if matches!(state.ui.lock().current_page(), PageState::Queue { .. }) {
+                    let queue = self.current_user_queue().await?;
+                    state.player.write().queue = Some(queue);
+                }
             }
-            ClientRequest::AddTrackToPlaylist(playlist_id, track_id) => {
-                self.add_track_to_playlist(state, &playlist_id, &track_id)
+            ClientRequest::AddTracksToPlaylist(playlist_id, track_ids) => {
+                self.add_tracks_to_playlist(state, &playlist_id, &track_ids)
                     .await?;
             }
-            ClientRequest::DeleteTrackFromPlaylist(playlist_id, track_id) => {
-                self.delete_track_from_playlist(state, &playlist_id, &track_id)
+            ClientRequest::DeleteTracksFromPlaylist(playlist_id, track_ids) => {
+                self.delete_tracks_from_playlist(state, &playlist_id, &track_ids)
                     .await?;
             }
+            ClientRequest::MovePlaylistTrack(playlist_id, from, to) => {
+                let lock = self.playlist_reorder_lock.lock().await;
+                if let Err(err) = self
+                    .move_playlist_track(state, &playlist_id, from, to)
+                    .await
+                {
+                    // The cached playlist may contain other track moves that are applied optimistically
+                    // and are waiting for the lock, so the failed move cannot be rolled back.
+                    // Instead, the cached playlist is invalidated, which makes the waiting moves fail,
+                    // and then reloaded.
+                    let is_cached = state
+                        .data
+                        .write()
+                        .caches
+                        .context
+                        .pop(&playlist_id.uri())
+                        .is_some();
+                    drop(lock);
+                    if is_cached {
+                        if let Err(err) = self
+                            .get_context(state, ContextId::Playlist(playlist_id))
+                            .await
+                        {
+                            tracing::warn!("Failed to reload the playlist: {err:#}");
+                        }
+                    }
+                    return Err(err);
+                }
+            }
             ClientRequest::AddToLibrary(item) => {
                 self.add_to_library(state, item).await?;
             }
+            ClientRequest::CreatePlaylist(details, track_ids) => {
+                self.create_playlist(state, details, &track_ids).await?;
+            }
+            ClientRequest::EditPlaylist(playlist_id, details) => {
+                self.edit_playlist(state, &playlist_id, details).await?;
+            }
             ClientRequest::DeleteFromLibrary(id) => {
                 self.delete_from_library(state, id).await?;
             }
+            ClientRequest::AddTracksToLibrary(tracks) => {
+                self.add_tracks_to_library(state, tracks).await?;
+            }
+            ClientRequest::DeleteTracksFromLibrary(track_ids) => {
+                self.delete_tracks_from_library(state, &track_ids).await?;
+            }
         };
 
+        if should_persist_data {
+            self.persist_pub.send(()).unwrap_or_default();
+        }
+
         tracing::info!(
             "successfully handled the client request, took: {}ms",
             timer.elapsed().unwrap().as_millis()
@@ -316,6 +552,109 @@ impl Client {
         Ok(())
     }
 
+    /// gets a context's data and stores it into the context cache.
+    /// A cached context is only refetched if it's loaded from the persisted cache and is outdated.
+    async fn get_context(&self, state: &SharedState, context: ContextId) -> Result<()> {
+        let uri = context.uri();
+
+        // a context loaded from the persisted cache needs to be revalidated
+        // before being used in the current session
+        let is_persisted = state.data.write().caches.persisted_contexts.remove(&uri);
+        // the outdated context, which is restored if it fails to be refetched
+        let mut outdated_context = None;
+        if is_persisted {
+            match self.is_context_up_to_date(state, &context).await {
+                Ok(true) => {}
+                Ok(false) => {
+                    tracing::info!("Context ({uri}) is outdated, refetch it");
+                    outdated_context = state.data.write().caches.context.pop(&uri);
+                }
+                Err(err) => {
+                    tracing::warn!("Failed to revalidate context ({uri}), use the cached data instead: {err:#}");
+                }
+            }
+        }
+
+        if !state.data.read().caches.context.contains(&uri) {
+            {
+                // the refetched context's tracks are in the context's original order
+                let mut data = state.data.write();
+                data.caches.sorted_contexts.remove(&uri);
+                data.caches.filtered_contexts.remove(&uri);
+                data.caches.audio_features_contexts.remove(&uri);
+            }
+
+            // playlist, album and show contexts are loaded incrementally into the cache
+            let result = match context {
+                ContextId::Playlist(playlist_id) => {
+                    self.playlist_context(state, &playlist_id).await
+                }
+                ContextId::Album(album_id) => self.album_context(state, &album_id).await,
+                ContextId::Show(show_id) => self.show_context(state, &show_id).await,
+                ContextId::Artist(artist_id) => {
+                    self.artist_context(&artist_id).await.map(|context| {
+                        state.data.write().caches.context.put(uri.clone(), context);
+                    })
+                }
+            };
+
+            let mut data = state.data.write();
+            data.caches.incomplete_contexts.remove(&uri);
+            if let Err(err) = result {
+                // remove the partially loaded context to refetch it later
+                data.caches.context.pop(&uri);
+                match outdated_context {
+                    None => return Err(err),
+                    Some(context) => {
+                        // keep using the outdated context (e.g. when the network is down),
+                        // which is revalidated again on the next request
+                        tracing::warn!("Failed to refetch context ({uri}), use the cached data instead: {err:#}");
+                        data.caches.context.put(uri.clone(), context);
+                        data.caches.persisted_contexts.insert(uri);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// checks if a cached context is still up-to-date
+    async fn is_context_up_to_date(
+        &self,
+        state: &SharedState,
+        context: &ContextId,
+    ) -> Result<bool> {
+        match context {
+            ContextId::Playlist(playlist_id) => {
+                #[derive(serde::Deserialize)]
+                struct PlaylistSnapshot {
+                    snapshot_id: String,
+                }
+
+                let snapshot_id = match state.data.read().caches.context.peek(&playlist_id.uri()) {
+                    Some(Context::Playlist { playlist, .. }) => playlist.snapshot_id.clone(),
+                    _ => return Ok(false),
+                };
+                // only request the playlist's snapshot ID to reduce the response size
+                let snapshot = self
+                    .internal_call::<PlaylistSnapshot>(&format!(
+                        "https://api.spotify.com/v1/playlists/{}?fields=snapshot_id",
+                        playlist_id.id()
+                    ))
+                    .await?;
+                Ok(snapshot.snapshot_id == snapshot_id)
+            }
+            // an album's data is not expected to change
+            ContextId::Album(_) => Ok(true),
+            // an artist's data such as top tracks or related artists changes over time,
+            // so it's always refetched
+            ContextId::Artist(_) => Ok(false),
+            // episodes' resume points change whenever the user listens to the show
+            ContextId::Show(_) => Ok(false),
+        }
+    }
+
     fn update_playback(&self, state: &SharedState) {
         // After handling a request that updates the player's playback,
         // update the playback state by making additional refresh requests.
@@ -382,7 +721,7 @@ impl Client {
         //    access to user's active devices.
         #[cfg(feature = "streaming")]
         {
-            if let Some(ref session) = self.spotify.session {
+            if let Some(session) = self.spotify.session() {
                 devices.push((
                     self.spotify.device.name.clone(),
                     session.device_id().to_string(),
@@ -405,18 +744,37 @@ impl Client {
         Ok(Some(devices.remove(id).1))
     }
 
-    /// gets the saved (liked) tracks of the current user
-    pub async fn current_user_saved_tracks(&self) -> Result<Vec<Track>> {
-        let first_page = self
-            .spotify
-            .current_user_saved_tracks_manual(None, Some(50), None)
-            .await?;
+    /// gets the saved (liked) tracks of the current user starting from the first page of saved tracks.
+    /// The tracks are loaded incrementally into the user data, page by page.
+    pub async fn current_user_saved_tracks(
+        &self,
+        state: &SharedState,
+        first_page: rspotify_model::Page<rspotify_model::SavedTrack>,
+    ) -> Result<()> {
+        // the previously loaded tracks, which are restored if the tracks fail to be loaded
+        let previous_tracks = {
+            let mut data = state.data.write();
+            data.caches.is_loading_saved_tracks = true;
+            std::mem::take(&mut data.user_data.saved_tracks)
+        };
+        let result = self
+            .for_each_paging_items(first_page, |items| {
+                let tracks = items.into_iter().filter_map(|t| {
+                    Track::try_from_full_track(t.track).map(|mut track| {
+                        track.added_at = t.added_at.timestamp() as u64;
+                        track
+                    })
+                });
+                state.data.write().user_data.saved_tracks.extend(tracks);
+            })
+            .await;
 
-        let tracks = self.all_paging_items(first_page).await?;
-        Ok(tracks
-            .into_iter()
-            .filter_map(|t| Track::try_from_full_track(t.track))
-            .collect())
+        let mut data = state.data.write();
+        data.caches.is_loading_saved_tracks = false;
+        if result.is_err() {
+            data.user_data.saved_tracks = previous_tracks;
+        }
+        result
     }
 
     /// gets the recently played tracks of the current user
@@ -454,6 +812,142 @@ impl Client {
             .collect())
     }
 
+    /// pre-fetches the audio files of the playback queue's next tracks into the audio cache.
+    /// Each track is downloaded in a background task, tracks already being pre-fetched are skipped.
+    #[cfg(feature = "streaming")]
+    fn prefetch_next_tracks(&self, state: &SharedState) {
+        let session = match self.spotify.session() {
+            None => return,
+            Some(session) => session,
+        };
+
+        let track_ids = match state.player.read().queue {
+            None => return,
+            // only the queue's next tracks are pre-fetched, episodes are skipped
+            Some(ref queue) => queue
+                .queue
+                .iter()
+                .take(state.app_config.device.prefetch_tracks)
+                .filter_map(|item| match item {
+                    QueueItem::Track(track) => Some(track.id.clone()),
+                    QueueItem::Episode(_) => None,
+                })
+                .collect::<Vec<_>>(),
+        };
+
+        for id in track_ids {
+            if !self.prefetching_tracks.lock().insert(id.clone()) {
+                continue;
+            }
+            let session = session.clone();
+            let bitrate = state.app_config.device.bitrate;
+            let prefetching_tracks = self.prefetching_tracks.clone();
+            tokio::task::spawn(async move {
+                if let Err(err) = streaming::prefetch_track(&session, &id, bitrate).await {
+                    tracing::warn!("Failed to pre-fetch track {id}: {err:#}");
+                }
+                prefetching_tracks.lock().remove(&id);
+            });
+        }
+    }
+
+    /// gets the current user's playback queue
+    pub async fn current_user_queue(&self) -> Result<PlaybackQueue> {
+        #[derive(serde::Deserialize)]
+        struct CurrentUserQueue {
+            currently_playing: Option<rspotify_model::PlayableItem>,
+            queue: Vec<rspotify_model::PlayableItem>,
+        }
+
+        // converts a `rspotify_model::PlayableItem` into `QueueItem`
+        fn into_queue_item(item: rspotify_model::PlayableItem) -> Option<QueueItem> {
+            match item {
+                rspotify_model::PlayableItem::Track(track) => {
+                    Track::try_from_full_track(track).map(QueueItem::Track)
+                }
+                rspotify_model::PlayableItem::Episode(episode) => {
+                    Some(QueueItem::Episode(episode.into()))
+                }
+            }
+        }
+
+        let queue = self
+            .internal_call::<CurrentUserQueue>("https://api.spotify.com/v1/me/player/queue")
+            .await?;
+        Ok(PlaybackQueue {
+            currently_playing: queue.currently_playing.and_then(into_queue_item),
+            queue: queue
+                .queue
+                .into_iter()
+                .filter_map(into_queue_item)
+                .collect(),
+        })
+    }
+
+    /// gets audio features of a cached context's tracks and stores them into the context's tracks.
+    /// Only tracks without audio features are requested.
+    pub async fn context_audio_features(&self, state: &SharedState, uri: &str) -> Result<()> {
+        #[derive(serde::Deserialize)]
+        struct AudioFeaturesPayload {
+            // audio features of a track can be unavailable
+            audio_features: Vec<Option<rspotify_model::AudioFeatures>>,
+        }
+
+        let track_ids = match state.data.read().caches.context.peek(uri) {
+            Some(context) => context
+                .tracks()
+                .iter()
+                .filter(|t| t.audio_features.is_none())
+                .map(|t| t.id.clone())
+                .collect::<Vec<_>>(),
+            None => return Ok(()),
+        };
+
+        let mut features = std::collections::HashMap::new();
+        for track_ids in track_ids.chunks(AUDIO_FEATURES_LIMIT) {
+            let url = format!(
+                "https://api.spotify.com/v1/audio-features?ids={}",
+                crate::utils::map_join(track_ids, |id| id.id(), ",")
+            );
+            let payload = self.internal_call::<AudioFeaturesPayload>(&url).await?;
+            features.extend(
+                payload
+                    .audio_features
+                    .into_iter()
+                    .flatten()
+                    .map(|f| (f.id.id().to_string(), AudioFeatures::from(f))),
+            );
+        }
+        tracing::info!("Got audio features of {} tracks in {uri}", features.len());
+
+        let mut data = state.data.write();
+        if let Some(tracks) = data
+            .caches
+            .context
+            .peek_mut(uri)
+            .and_then(|context| context.tracks_mut())
+        {
+            for track in tracks {
+                if let Some(f) = features.get(track.id.id()) {
+                    track.audio_features = Some(*f);
+                }
+            }
+        }
+        // audio features of the remaining tracks of an incomplete context need to be requested later
+        if data.caches.incomplete_contexts.contains(uri) {
+            data.caches.audio_features_contexts.remove(uri);
+        }
+        Ok(())
+    }
+
+    /// gets all saved shows of the current user
+    pub async fn current_user_saved_shows(&self) -> Result<Vec<Show>> {
+        let first_page = self.spotify.get_saved_show_manual(Some(50), None).await?;
+
+        let shows = self.all_paging_items(first_page).await?;
+        Ok(shows.into_iter().map(|s| s.show.into()).collect())
+    }
+
     /// gets all playlists of the current user
     pub async fn current_user_playlists(&self) -> Result<Vec<Playlist>> {
         let first_page = self
@@ -465,6 +959,17 @@ impl Client {
         Ok(playlists.into_iter().map(|p| p.into()).collect())
     }
 
+    /// gets all public playlists of a user
+    pub async fn user_public_playlists(&self, user_id: &UserId) -> Result<Vec<Playlist>> {
+        let first_page = self
+            .spotify
+            .user_playlists_manual(user_id, Some(50), None)
+            .await?;
+
+        let playlists = self.all_paging_items(first_page).await?;
+        Ok(playlists.into_iter().map(|p| p.into()).collect())
+    }
+
     /// gets all followed artists of the current user
     pub async fn current_user_followed_artists(&self) -> Result<Vec<Artist>> {
         let first_page = self
@@ -502,35 +1007,17 @@ impl Client {
         Ok(albums.into_iter().map(|a| a.album.into()).collect())
     }
 
-    /// gets all albums of an artist
-    pub async fn artist_albums(&self, artist_id: &ArtistId) -> Result<Vec<Album>> {
-        let mut singles = {
-            let first_page = self
-                .spotify
-                .artist_albums_manual(
-                    artist_id,
-                    Some(&rspotify_model::AlbumType::Single),
-                    None,
-                    Some(50),
-                    None,
-                )
-                .await?;
-            self.all_paging_items(first_page).await
-        }?;
-        let mut albums = {
-            let first_page = self
-                .spotify
-                .artist_albums_manual(
-                    artist_id,
-                    Some(&rspotify_model::AlbumType::Album),
-                    None,
-                    Some(50),
-                    None,
-                )
-                .await?;
-            self.all_paging_items(first_page).await
-        }?;
-        albums.append(&mut singles);
+    /// gets all albums of an artist with a given album type
+    pub async fn artist_albums(
+        &self,
+        artist_id: &ArtistId,
+        album_type: rspotify_model::AlbumType,
+    ) -> Result<Vec<Album>> {
+        let first_page = self
+            .spotify
+            .artist_albums_manual(artist_id, Some(&album_type), None, Some(50), None)
+            .await?;
+        let albums = self.all_paging_items(first_page).await?;
 
         // converts `rspotify_model::SimplifiedAlbum` into `state::Album`
         let albums = albums
@@ -559,6 +1046,11 @@ impl Client {
                         .start_context_playback(&id, device_id, offset, None)
                         .await?
                 }
+                ContextId::Show(id) => {
+                    self.spotify
+                        .start_context_playback(&id, device_id, offset, None)
+                        .await?
+                }
             },
             Playback::URIs(track_ids, offset) => {
                 self.spotify
@@ -578,46 +1070,50 @@ impl Client {
         Ok(())
     }
 
-    /// gets recommendation tracks from a recommendation seed
-    pub async fn recommendations(&self, seed: &SeedItem) -> Result<Vec<Track>> {
-        let attributes = vec![];
-
-        let tracks = match seed {
-            SeedItem::Artist(artist) => {
-                self.spotify
-                    .recommendations(
-                        attributes,
-                        Some(vec![&artist.id]),
-                        None::<Vec<_>>,
-                        None::<Vec<_>>,
-                        None,
-                        Some(50),
-                    )
-                    .await?
-                    .tracks
+    /// gets recommendation tracks from a recommendation query
+    pub async fn recommendations(&self, query: &RecommendationQuery) -> Result<Vec<Track>> {
+        let mut seed_artists = vec![];
+        let mut seed_tracks = vec![];
+        let mut seed_genres = vec![];
+        for seed in &query.seeds {
+            match seed {
+                SeedItem::Track(track) => seed_tracks.push(track),
+                SeedItem::Artist(artist) => seed_artists.push(&artist.id),
+                SeedItem::Genre(genre) => seed_genres.push(genre.as_str()),
             }
-            SeedItem::Track(track) => {
-                self.spotify
-                    .recommendations(
-                        attributes,
-                        Some(track.artists.iter().map(|a| &a.id).collect::<Vec<_>>()),
-                        None::<Vec<_>>,
-                        Some(vec![&track.id]),
-                        None,
-                        Some(50),
-                    )
-                    .await?
-                    .tracks
-            }
-        };
+        }
+        // a single track seed also uses the track's artists as seeds
+        // to get recommendations similar to a "track radio"
+        if let [SeedItem::Track(track)] = query.seeds.as_slice() {
+            seed_artists.extend(
+                track
+                    .artists
+                    .iter()
+                    .map(|a| &a.id)
+                    .take(MAX_RECOMMENDATION_SEEDS - 1),
+            );
+        }
+
+        let tracks = self
+            .spotify
+            .recommendations(
+                query.attributes.iter().map(|&a| a.into()),
+                Some(seed_artists),
+                Some(seed_genres),
+                Some(seed_tracks.iter().map(|t| &t.id).collect::<Vec<_>>()),
+                None,
+                Some(50),
+            )
+            .await?
+            .tracks;
 
         let mut tracks = tracks
             .into_iter()
             .filter_map(Track::try_from_simplified_track)
             .collect::<Vec<_>>();
 
-        // for track recommendation seed, add the track seed to the returned recommended tracks
-        if let SeedItem::Track(track) = seed {
+        // add the track seeds to the returned recommended tracks
+        for track in seed_tracks.into_iter().rev() {
             let mut seed_track = track.clone();
             // recommended tracks returned from the API are represented by `SimplifiedTrack` struct,
             // which doesn't have `album` field specified. So, we need to change the seed track's
@@ -629,107 +1125,203 @@ impl Client {
         Ok(tracks)
     }
 
+    /// continues the finished playback with tracks recommended from the recently played tracks
+    pub async fn autoplay(&self, state: &SharedState, recent_tracks: Vec<Track>) -> Result<()> {
+        let query = RecommendationQuery {
+            seeds: recent_tracks.into_iter().map(SeedItem::Track).collect(),
+            attributes: vec![],
+        };
+        let track_ids = self
+            .recommendations(&query)
+            .await?
+            .into_iter()
+            .map(|t| t.id)
+            // seed tracks are included in the recommended tracks but they have been played already
+            .filter(|id| !query.seeds.iter().any(|s| s.uri() == id.uri()))
+            .collect::<Vec<_>>();
+        if track_ids.is_empty() {
+            return Ok(());
+        }
+
+        tracing::info!(
+            "Autoplaying {} tracks recommended from {}",
+            track_ids.len(),
+            query.desc()
+        );
+        self.handle_player_request(
+            state,
+            PlayerRequest::StartPlayback(Playback::URIs(track_ids, None)),
+        )
+        .await?;
+        self.update_playback(state);
+        Ok(())
+    }
+
     /// searchs for items (tracks, artists, albums, playlists) that match a given query string.
+    /// Item types that don't support the query's field filters are not searched.
     pub async fn search(&self, query: &str) -> Result<SearchResults> {
-        let (track_result, artist_result, album_result, playlist_result) = tokio::try_join!(
-            self.search_specific_type(query, &rspotify_model::SearchType::Track),
-            self.search_specific_type(query, &rspotify_model::SearchType::Artist),
-            self.search_specific_type(query, &rspotify_model::SearchType::Album),
-            self.search_specific_type(query, &rspotify_model::SearchType::Playlist)
+        let search = |search_type: SearchType| async move {
+            if search_type.is_searchable_by(query) {
+                self.search_specific_type(query, search_type, 0).await
+            } else {
+                Ok(SearchResults::default())
+            }
+        };
+
+        let (mut results, album_results, artist_results, playlist_results) = tokio::try_join!(
+            search(SearchType::Track),
+            search(SearchType::Album),
+            search(SearchType::Artist),
+            search(SearchType::Playlist),
         )?;
+        results.merge(album_results);
+        results.merge(artist_results);
+        results.merge(playlist_results);
+
+        Ok(results)
+    }
+
+    /// searchs for items of a specific type starting from an offset
+    async fn search_specific_type(
+        &self,
+        query: &str,
+        search_type: SearchType,
+        offset: u32,
+    ) -> Result<SearchResults> {
+        let result = self
+            .spotify
+            .search(query, &search_type.into(), None, None, None, Some(offset))
+            .await?;
 
-        let (tracks, artists, albums, playlists) = (
-            match track_result {
-                rspotify_model::SearchResult::Tracks(p) => p
+        let mut results = SearchResults::default();
+        let (total, limit, has_next) = match result {
+            rspotify_model::SearchResult::Tracks(p) => {
+                results.tracks = p
                     .items
                     .into_iter()
                     .filter_map(Track::try_from_full_track)
-                    .collect(),
-                _ => anyhow::bail!("expect a track search result"),
-            },
-            match artist_result {
-                rspotify_model::SearchResult::Artists(p) => {
-                    p.items.into_iter().map(|a| a.into()).collect()
-                }
-                _ => anyhow::bail!("expect an artist search result"),
-            },
-            match album_result {
-                rspotify_model::SearchResult::Albums(p) => p
+                    .collect();
+                (p.total, p.limit, p.next.is_some())
+            }
+            rspotify_model::SearchResult::Artists(p) => {
+                results.artists = p.items.into_iter().map(|a| a.into()).collect();
+                (p.total, p.limit, p.next.is_some())
+            }
+            rspotify_model::SearchResult::Albums(p) => {
+                results.albums = p
                     .items
                     .into_iter()
                     .filter_map(Album::try_from_simplified_album)
-                    .collect(),
-                _ => anyhow::bail!("expect an album search result"),
-            },
-            match playlist_result {
-                rspotify_model::SearchResult::Playlists(p) => {
-                    p.items.into_iter().map(|i| i.into()).collect()
-                }
-                _ => anyhow::bail!("expect a playlist search result"),
-            },
-        );
+                    .collect();
+                (p.total, p.limit, p.next.is_some())
+            }
+            rspotify_model::SearchResult::Playlists(p) => {
+                results.playlists = p.items.into_iter().map(|i| i.into()).collect();
+                (p.total, p.limit, p.next.is_some())
+            }
+            _ => anyhow::bail!("expect a {search_type:?} search result"),
+        };
 
-        Ok(SearchResults {
-            tracks,
-            artists,
-            albums,
-            playlists,
-        })
+        results.totals.insert(search_type, total);
+        if has_next {
+            results.next_offsets.insert(search_type, offset + limit);
+        }
+        Ok(results)
     }
 
-    async fn search_specific_type(
+    /// loads the next page of a search query's results of a specific type
+    /// and appends the page into the cached search results
+    pub async fn search_more(
         &self,
+        state: &SharedState,
         query: &str,
-        _type: &rspotify_model::SearchType,
-    ) -> Result<rspotify_model::SearchResult> {
-        Ok(self
-            .spotify
-            .search(query, _type, None, None, None, None)
-            .await?)
+        search_type: SearchType,
+    ) -> Result<()> {
+        // the next offset is taken out of the cached results to avoid
+        // loading the same page more than once
+        let offset = match state.data.write().caches.search.peek_mut(query) {
+            Some(results) => results.next_offsets.remove(&search_type),
+            None => None,
+        };
+        let offset = match offset {
+            Some(offset) => offset,
+            None => return Ok(()),
+        };
+
+        let results = match self.search_specific_type(query, search_type, offset).await {
+            Ok(results) => results,
+            Err(err) => {
+                // restore the next offset to allow retrying
+                if let Some(results) = state.data.write().caches.search.peek_mut(query) {
+                    results.next_offsets.insert(search_type, offset);
+                }
+                return Err(err);
+            }
+        };
+        if let Some(cached_results) = state.data.write().caches.search.peek_mut(query) {
+            cached_results.merge(results);
+        }
+        Ok(())
     }
 
-    /// adds track to queue
-    pub async fn add_track_to_queue(&self, track_id: &TrackId) -> Result<()> {
-        Ok(self.spotify.add_item_to_queue(track_id, None).await?)
+    /// adds tracks to queue.
+    /// Spotify's queue API only accepts one item per request, so tracks are added one by one.
+    pub async fn add_tracks_to_queue(&self, track_ids: &[TrackId]) -> Result<()> {
+        for track_id in track_ids {
+            self.spotify.add_item_to_queue(track_id, None).await?;
+        }
+        Ok(())
     }
 
-    /// adds track to a playlist
-    pub async fn add_track_to_playlist(
+    /// adds tracks to a playlist
+    pub async fn add_tracks_to_playlist(
         &self,
         state: &SharedState,
         playlist_id: &PlaylistId,
-        track_id: &TrackId,
+        track_ids: &[TrackId],
     ) -> Result<()> {
-        let dyn_track_id = track_id as &dyn PlayableId;
+        for track_ids in track_ids.chunks(PLAYLIST_ITEMS_LIMIT) {
+            let dyn_track_ids = track_ids
+                .iter()
+                .map(|id| id as &dyn PlayableId)
+                .collect::<Vec<_>>();
 
-        // remove all the occurrences of the track to ensure no duplication in the playlist
-        self.spotify
-            .playlist_remove_all_occurrences_of_items(playlist_id, vec![dyn_track_id], None)
-            .await?;
+            // remove all the occurrences of the tracks to ensure no duplication in the playlist
+            self.spotify
+                .playlist_remove_all_occurrences_of_items(playlist_id, dyn_track_ids.clone(), None)
+                .await?;
 
-        self.spotify
-            .playlist_add_items(playlist_id, vec![dyn_track_id], None)
-            .await?;
+            self.spotify
+                .playlist_add_items(playlist_id, dyn_track_ids, None)
+                .await?;
+        }
 
-        // After adding a new track to a playlist, remove the cache of that playlist to force refetching new data
+        // After adding new tracks to a playlist, remove the cache of that playlist to force refetching new data
         state.data.write().caches.context.pop(&playlist_id.uri());
 
         Ok(())
     }
 
-    /// removes a track from a playlist
-    pub async fn delete_track_from_playlist(
+    /// removes tracks from a playlist
+    pub async fn delete_tracks_from_playlist(
         &self,
         state: &SharedState,
         playlist_id: &PlaylistId,
-        track_id: &TrackId,
+        track_ids: &[TrackId],
     ) -> Result<()> {
-        let dyn_track_id = track_id as &dyn PlayableId;
-
-        // remove all the occurrences of the track to ensure no duplication in the playlist
-        self.spotify
-            .playlist_remove_all_occurrences_of_items(playlist_id, vec![dyn_track_id], None)
-            .await?;
+        for track_ids in track_ids.chunks(PLAYLIST_ITEMS_LIMIT) {
+            let dyn_track_ids = track_ids
+                .iter()
+                .map(|id| id as &dyn PlayableId)
+                .collect::<Vec<_>>();
+
+            // remove all the occurrences of the tracks to ensure no duplication in the playlist
+            let result = self
+                .spotify
+                .playlist_remove_all_occurrences_of_items(playlist_id, dyn_track_ids, None)
+                .await?;
+            update_playlist_snapshot_id(state, playlist_id, result.snapshot_id);
+        }
 
         // After making a delete request, update the playlist in-memory data stored inside the app caches.
         if let Some(Context::Playlist { tracks, .. }) = state
@@ -739,30 +1331,46 @@ impl Client {
             .context
             .get_mut(&playlist_id.uri())
         {
-            tracks.retain(|t| t.id != *track_id);
+            tracks.retain(|t| !track_ids.contains(&t.id));
         }
 
         Ok(())
     }
 
+    /// moves a playlist's track from position `from` to position `to`.
+    /// The track move should already be applied to the cached playlist's tracks.
+    pub async fn move_playlist_track(
+        &self,
+        state: &SharedState,
+        playlist_id: &PlaylistId,
+        from: usize,
+        to: usize,
+    ) -> Result<()> {
+        let snapshot_id = match state.data.read().caches.context.peek(&playlist_id.uri()) {
+            Some(Context::Playlist { playlist, .. }) => playlist.snapshot_id.clone(),
+            _ => anyhow::bail!("playlist {playlist_id} is not found in the cache"),
+        };
+
+        // `insert_before` is the position of the track that the moved track
+        // is inserted before, calculated based on the tracks' order before the move
+        let insert_before = if to > from { to + 1 } else { to };
+        let result = self
+            .spotify
+            .playlist_reorder_items_once(playlist_id, from, insert_before, 1, &snapshot_id)
+            .await?;
+        tracing::info!(
+            "Moved the track at position {from} of the playlist {playlist_id} to position {to}"
+        );
+
+        update_playlist_snapshot_id(state, playlist_id, result.snapshot_id);
+        Ok(())
+    }
+
     /// adds a Spotify item to current user's library.
     /// Before adding new item, the function checks if that item already exists in the library
     /// to avoid adding a duplicated item.
     pub async fn add_to_library(&self, state: &SharedState, item: Item) -> Result<()> {
         match item {
-            Item::Track(track) => {
-                let contains = self
-                    .spotify
-                    .current_user_saved_tracks_contains(vec![&track.id])
-                    .await?;
-                if !contains[0] {
-                    self.spotify
-                        .current_user_saved_tracks_add(vec![&track.id])
-                        .await?;
-                    // update the in-memory `user_data`
-                    state.data.write().user_data.saved_tracks.insert(0, track);
-                }
-            }
             Item::Album(album) => {
                 let contains = self
                     .spotify
@@ -817,18 +1425,66 @@ impl Client {
         Ok(())
     }
 
+    /// adds tracks to current user's liked tracks.
+    /// Similar to `add_to_library`, tracks that are already liked are not added again.
+    pub async fn add_tracks_to_library(
+        &self,
+        state: &SharedState,
+        tracks: Vec<Track>,
+    ) -> Result<()> {
+        for tracks in tracks.chunks(SAVED_TRACKS_LIMIT) {
+            let contains = self
+                .spotify
+                .current_user_saved_tracks_contains(tracks.iter().map(|t| &t.id))
+                .await?;
+            let new_tracks = tracks
+                .iter()
+                .zip(contains)
+                .filter(|(_, contains)| !contains)
+                .map(|(t, _)| t.clone())
+                .collect::<Vec<_>>();
+            if new_tracks.is_empty() {
+                continue;
+            }
+
+            self.spotify
+                .current_user_saved_tracks_add(new_tracks.iter().map(|t| &t.id))
+                .await?;
+            // update the in-memory `user_data`
+            state
+                .data
+                .write()
+                .user_data
+                .saved_tracks
+                .splice(0..0, new_tracks);
+        }
+        Ok(())
+    }
+
+    /// removes tracks from current user's liked tracks
+    pub async fn delete_tracks_from_library(
+        &self,
+        state: &SharedState,
+        track_ids: &[TrackId],
+    ) -> Result<()> {
+        for track_ids in track_ids.chunks(SAVED_TRACKS_LIMIT) {
+            self.spotify
+                .current_user_saved_tracks_delete(track_ids)
+                .await?;
+            // update the in-memory `user_data` after the tracks are removed from Spotify
+            state
+                .data
+                .write()
+                .user_data
+                .saved_tracks
+                .retain(|t| !track_ids.contains(&t.id));
+        }
+        Ok(())
+    }
+
     // deletes a Spotify item from user's library
     pub async fn delete_from_library(&self, state: &SharedState, id: ItemId) -> Result<()> {
         match id {
-            ItemId::Track(id) => {
-                state
-                    .data
-                    .write()
-                    .user_data
-                    .saved_tracks
-                    .retain(|t| t.id != id);
-                self.spotify.current_user_saved_tracks_delete(&[id]).await?;
-            }
             ItemId::Album(id) => {
                 state
                     .data
@@ -860,35 +1516,145 @@ impl Client {
         Ok(())
     }
 
-    /// gets a playlist context data
-    async fn playlist_context(&self, playlist_id: &PlaylistId) -> Result<Context> {
+    /// creates a new playlist for the current user, then adds tracks (if any) to the playlist
+    pub async fn create_playlist(
+        &self,
+        state: &SharedState,
+        details: PlaylistDetails,
+        track_ids: &[TrackId],
+    ) -> Result<()> {
+        let user_id = match state.data.read().user_data.user {
+            Some(ref user) => user.id.clone(),
+            None => anyhow::bail!("failed to create a playlist: the current user is not found"),
+        };
+
+        let playlist = self
+            .spotify
+            .user_playlist_create(
+                &user_id,
+                details.name.as_deref().unwrap_or_default(),
+                details.public,
+                details.collaborative,
+                details.description.as_deref(),
+            )
+            .await?;
+        tracing::info!("Created a new playlist: {}", playlist.id);
+
+        for track_ids in track_ids.chunks(PLAYLIST_ITEMS_LIMIT) {
+            let dyn_track_ids = track_ids.iter().map(|id| id as &dyn PlayableId);
+            self.spotify
+                .playlist_add_items(&playlist.id, dyn_track_ids, None)
+                .await?;
+        }
+
+        // a newly created playlist is placed at the top of the user's playlists
+        state
+            .data
+            .write()
+            .user_data
+            .playlists
+            .insert(0, playlist.into());
+        Ok(())
+    }
+
+    /// edits a playlist's details, then updates the playlist stored in
+    /// the user data and the context cache in place
+    pub async fn edit_playlist(
+        &self,
+        state: &SharedState,
+        playlist_id: &PlaylistId,
+        details: PlaylistDetails,
+    ) -> Result<()> {
+        if details.name.is_none()
+            && details.description.is_none()
+            && details.public.is_none()
+            && details.collaborative.is_none()
+        {
+            return Ok(());
+        }
+
+        self.spotify
+            .playlist_change_detail(
+                playlist_id,
+                details.name.as_deref(),
+                details.public,
+                details.description.as_deref(),
+                details.collaborative,
+            )
+            .await?;
+        tracing::info!("Edited the playlist {playlist_id}: {details:?}");
+
+        let mut data = state.data.write();
+        if let Some(playlist) = data
+            .user_data
+            .playlists
+            .iter_mut()
+            .find(|p| p.id == *playlist_id)
+        {
+            playlist.update_details(&details);
+        }
+        if let Some(Context::Playlist { playlist, .. }) =
+            data.caches.context.peek_mut(&playlist_id.uri())
+        {
+            playlist.update_details(&details);
+        }
+        Ok(())
+    }
+
+    /// gets a playlist context data.
+    /// The context is put into the cache right after getting the first page of the playlist's tracks,
+    /// the remaining tracks are then loaded incrementally into the cached context, page by page.
+    async fn playlist_context(&self, state: &SharedState, playlist_id: &PlaylistId) -> Result<()> {
         let playlist_uri = playlist_id.uri();
         tracing::info!("Get playlist context: {}", playlist_uri);
 
         let playlist = self.spotify.playlist(playlist_id, None, None).await?;
-
-        // get the playlist's tracks
         let first_page = playlist.tracks.clone();
-        let tracks = self
-            .all_paging_items(first_page)
-            .await?
-            .into_iter()
-            .filter_map(|item| match item.track {
-                Some(rspotify_model::PlayableItem::Track(track)) => {
-                    Track::try_from_full_track(track)
-                }
-                _ => None,
-            })
-            .collect::<Vec<_>>();
 
-        Ok(Context::Playlist {
-            playlist: playlist.into(),
-            tracks,
+        put_incomplete_context(
+            state,
+            playlist_uri.clone(),
+            Context::Playlist {
+                playlist: playlist.into(),
+                tracks: vec![],
+            },
+        );
+
+        // get the playlist's tracks
+        self.for_each_paging_items(first_page, |items| {
+            let n_items = items.len();
+            let tracks = items
+                .into_iter()
+                .filter_map(|item| match item.track {
+                    Some(rspotify_model::PlayableItem::Track(track)) => {
+                        Track::try_from_full_track(track).map(|mut track| {
+                            track.added_at = item
+                                .added_at
+                                .map(|t| t.timestamp() as u64)
+                                .unwrap_or_default();
+                            track
+                        })
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if tracks.len() < n_items {
+                // positions of the cached tracks no longer match the playlist's item positions
+                state
+                    .data
+                    .write()
+                    .caches
+                    .filtered_contexts
+                    .insert(playlist_uri.clone());
+            }
+            append_context_tracks(state, &playlist_uri, tracks.into_iter());
         })
+        .await
     }
 
-    /// gets an album context data
-    async fn album_context(&self, album_id: &AlbumId) -> Result<Context> {
+    /// gets an album context data.
+    /// Similar to a playlist context, the album's tracks are loaded incrementally into the cached context.
+    async fn album_context(&self, state: &SharedState, album_id: &AlbumId) -> Result<()> {
         let album_uri = album_id.uri();
         tracing::info!("Get album context: {}", album_uri);
 
@@ -898,12 +1664,18 @@ impl Client {
         // converts `rspotify_model::FullAlbum` into `state::Album`
         let album: Album = album.into();
 
+        put_incomplete_context(
+            state,
+            album_uri.clone(),
+            Context::Album {
+                album: album.clone(),
+                tracks: vec![],
+            },
+        );
+
         // get the album's tracks
-        let tracks = self
-            .all_paging_items(first_page)
-            .await?
-            .into_iter()
-            .filter_map(|t| {
+        self.for_each_paging_items(first_page, |items| {
+            let tracks = items.into_iter().filter_map(|t| {
                 // simplified track doesn't have album so
                 // we need to manually include one during
                 // converting into `state::Track`
@@ -911,10 +1683,39 @@ impl Client {
                     t.album = Some(album.clone());
                     t
                 })
-            })
-            .collect::<Vec<_>>();
+            });
+            append_context_tracks(state, &album_uri, tracks);
+        })
+        .await
+    }
+
+    /// gets a show context data.
+    /// Similar to a playlist context, the show's episodes are loaded incrementally into the cached context.
+    async fn show_context(&self, state: &SharedState, show_id: &ShowId) -> Result<()> {
+        let show_uri = show_id.uri();
+        tracing::info!("Get show context: {}", show_uri);
+
+        let show = self.spotify.get_a_show(show_id, None).await?;
+        let first_page = show.episodes.clone();
+
+        put_incomplete_context(
+            state,
+            show_uri.clone(),
+            Context::Show {
+                show: show.into(),
+                episodes: vec![],
+            },
+        );
 
-        Ok(Context::Album { album, tracks })
+        // get the show's episodes
+        self.for_each_paging_items(first_page, |items| {
+            if let Some(Context::Show { episodes, .. }) =
+                state.data.write().caches.context.peek_mut(&show_uri)
+            {
+                episodes.extend(items.into_iter().map(Episode::from));
+            }
+        })
+        .await
     }
 
     /// gets an artist context data
@@ -941,12 +1742,20 @@ impl Client {
             .map(|a| a.into())
             .collect::<Vec<_>>();
 
-        let albums = self.artist_albums(artist_id).await?;
+        let (albums, singles, compilations, appears_on) = tokio::try_join!(
+            self.artist_albums(artist_id, rspotify_model::AlbumType::Album),
+            self.artist_albums(artist_id, rspotify_model::AlbumType::Single),
+            self.artist_albums(artist_id, rspotify_model::AlbumType::Compilation),
+            self.artist_albums(artist_id, rspotify_model::AlbumType::AppearsOn),
+        )?;
 
         Ok(Context::Artist {
             artist,
             top_tracks,
             albums,
+            singles,
+            compilations,
+            appears_on,
             related_artists,
         })
     }
@@ -958,17 +1767,27 @@ impl Client {
         T: serde::de::DeserializeOwned,
     {
         let access_token = self.spotify.access_token().await?;
-        Ok(self
-            .http
-            .get(url)
-            .header(
-                reqwest::header::AUTHORIZATION,
-                format!("Bearer {}", access_token),
-            )
-            .send()
-            .await?
-            .json::<T>()
-            .await?)
+        let response = self
+            .spotify
+            .with_retry(true, || async {
+                let response = self
+                    .http
+                    .get(url)
+                    .header(
+                        reqwest::header::AUTHORIZATION,
+                        format!("Bearer {}", access_token),
+                    )
+                    .send()
+                    .await
+                    .map_err(rspotify::http::HttpError::from)?;
+                if response.status().is_success() {
+                    Ok(response)
+                } else {
+                    Err(rspotify::http::HttpError::StatusCode(response).into())
+                }
+            })
+            .await?;
+        Ok(response.json::<T>().await?)
     }
 
     /// gets all paging items starting from a pagination object of the first page
@@ -976,14 +1795,31 @@ impl Client {
     where
         T: serde::de::DeserializeOwned,
     {
-        let mut items = first_page.items;
+        let mut items = vec![];
+        self.for_each_paging_items(first_page, |mut page_items| items.append(&mut page_items))
+            .await?;
+        Ok(items)
+    }
+
+    /// gets all paging items starting from a pagination object of the first page.
+    /// Items of a page are passed to the `f` function as soon as the page is retrieved.
+    async fn for_each_paging_items<T, F>(
+        &self,
+        first_page: rspotify_model::Page<T>,
+        mut f: F,
+    ) -> Result<()>
+    where
+        T: serde::de::DeserializeOwned,
+        F: FnMut(Vec<T>),
+    {
+        f(first_page.items);
         let mut maybe_next = first_page.next;
         while let Some(url) = maybe_next {
-            let mut next_page = self.internal_call::<rspotify_model::Page<T>>(&url).await?;
-            items.append(&mut next_page.items);
+            let next_page = self.internal_call::<rspotify_model::Page<T>>(&url).await?;
+            f(next_page.items);
             maybe_next = next_page.next;
         }
-        Ok(items)
+        Ok(())
     }
 
     /// gets all cursor-based paging items starting from a pagination object of the first page
@@ -1008,7 +1844,16 @@ impl Client {
 
     /// updates the current playback state
     pub async fn update_current_playback_state(&self, state: &SharedState) -> Result<()> {
-        let playback = self.spotify.current_playback(None, None::<Vec<_>>).await?;
+        let playback = self
+            .spotify
+            .current_playback(
+                None,
+                Some(&[
+                    rspotify_model::AdditionalType::Track,
+                    rspotify_model::AdditionalType::Episode,
+                ]),
+            )
+            .await?;
         let mut player = state.player.write();
 
         player.playback = playback;
@@ -1022,7 +1867,7 @@ impl Client {
         let url = state
             .player
             .read()
-            .current_playing_track_album_cover_url()
+            .current_playing_item_cover_url()
             .map(String::from);
 
         if let Some(url) = url {
@@ -1072,3 +1917,47 @@ impl Client {
         })
     }
 }
+
+/// puts a context whose tracks are not fully loaded into the application's cache
+fn put_incomplete_context(state: &SharedState, uri: String, context: Context) {
+    let mut data = state.data.write();
+    data.caches.incomplete_contexts.insert(uri.clone());
+    data.caches.context.put(uri, context);
+}
+
+/// updates the snapshot id of a playlist stored inside the application's user data and caches
+fn update_playlist_snapshot_id(state: &SharedState, playlist_id: &PlaylistId, snapshot_id: String) {
+    let mut data = state.data.write();
+    if let Some(playlist) = data
+        .user_data
+        .playlists
+        .iter_mut()
+        .find(|p| p.id == *playlist_id)
+    {
+        playlist.snapshot_id = snapshot_id.clone();
+    }
+    if let Some(Context::Playlist { playlist, .. }) =
+        data.caches.context.peek_mut(&playlist_id.uri())
+    {
+        playlist.snapshot_id = snapshot_id;
+    }
+}
+
+/// appends tracks to a context stored inside the application's cache
+fn append_context_tracks(state: &SharedState, uri: &str, tracks: impl Iterator<Item = Track>) {
+    if let Some(context) = state.data.write().caches.context.peek_mut(uri) {
+        if let Some(context_tracks) = context.tracks_mut() {
+            context_tracks.extend(tracks);
+        }
+    }
+}
+
+/// checks if the next tracks of the playback's queue should be pre-fetched into the audio cache,
+/// which is only done when the playback is played on the integrated device
+fn should_prefetch_tracks(state: &SharedState) -> bool {
+    let device = &state.app_config.device;
+    cfg!(feature = "streaming")
+        && device.audio_cache
+        && device.prefetch_tracks > 0
+        && state.player.read().is_playing_on_integrated_device()
+}
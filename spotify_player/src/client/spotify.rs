@@ -3,13 +3,38 @@ use librespot_core::session::Session;
 use maybe_async::maybe_async;
 use rspotify::{
     clients::{mutex::Mutex, BaseClient, OAuthClient},
-    http::HttpClient,
-    ClientResult, Config, Credentials, OAuth, Token,
+    http::{HttpClient, HttpError, Query},
+    model::{PlaylistId, PlaylistResult},
+    prelude::Id,
+    ClientError, ClientResult, Config, Credentials, OAuth, Token,
 };
-use std::{fmt, sync::Arc};
+use serde_json::Value;
+use std::{fmt, future::Future, sync::Arc, time::Duration};
 
 use crate::{config, token};
 
+/// the maximum number of concurrent in-flight Spotify API requests
+const MAX_CONCURRENT_REQUESTS: usize = 8;
+/// the maximum number of retries for a failed Spotify API request
+const MAX_RETRIES: u32 = 5;
+/// the backoff duration before the first retry, which is doubled after each retry
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+/// the maximum duration to wait before retrying a failed request,
+/// a request requiring a longer wait fails instead
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+#[derive(Clone, Debug)]
+/// A limiter to cap the number of concurrent in-flight API requests
+pub struct RequestLimiter(Arc<tokio::sync::Semaphore>);
+
+impl Default for RequestLimiter {
+    fn default() -> Self {
+        Self(Arc::new(tokio::sync::Semaphore::new(
+            MAX_CONCURRENT_REQUESTS,
+        )))
+    }
+}
+
 #[derive(Clone, Default)]
 /// A Spotify client to interact with Spotify API server
 pub struct Spotify {
@@ -20,7 +45,9 @@ pub struct Spotify {
     pub client_id: String,
     pub http: HttpClient,
     pub device: config::DeviceConfig,
-    pub session: Option<Session>,
+    /// the Librespot session, which is replaced upon reconnecting
+    pub session: Arc<parking_lot::RwLock<Option<Session>>>,
+    pub request_limiter: RequestLimiter,
 }
 
 impl fmt::Debug for Spotify {
@@ -47,12 +74,88 @@ impl Spotify {
             },
             token: Arc::new(Mutex::new(None)),
             http: HttpClient::default(),
-            session: Some(session),
+            session: Arc::new(parking_lot::RwLock::new(Some(session))),
+            request_limiter: RequestLimiter::default(),
             device,
             client_id,
         }
     }
 
+    /// makes a Spotify API request with a retry mechanism.
+    ///
+    /// A request is retried when it's rate limited (`429 Too Many Requests`),
+    /// in which case the `Retry-After` header is honoured, or when it fails
+    /// with a server error or a network error, in which case an exponential backoff is used.
+    /// Non-idempotent requests are only retried when they're rate limited.
+    /// A request fails without retrying if the delay before the retry exceeds `MAX_RETRY_DELAY`.
+    pub async fn with_retry<T, F, Fut>(&self, idempotent: bool, request: F) -> ClientResult<T>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = ClientResult<T>>,
+    {
+        let mut attempt = 0;
+        loop {
+            let result = {
+                // the semaphore is never closed, so acquiring a permit should never fail
+                let _permit = self.request_limiter.0.acquire().await;
+                request().await
+            };
+
+            let err = match result {
+                Ok(data) => return Ok(data),
+                Err(err) => err,
+            };
+            if attempt >= MAX_RETRIES {
+                return Err(err);
+            }
+            let delay = match retry_delay(&err, attempt, idempotent) {
+                Some(delay) => delay,
+                None => return Err(err),
+            };
+
+            tracing::warn!(
+                "Spotify API request failed: {err:#}, retrying in {delay:?} (attempt {}/{MAX_RETRIES})...",
+                attempt + 1
+            );
+            tokio::time::sleep(delay).await;
+            attempt += 1;
+        }
+    }
+
+    /// moves a range of a playlist's items to a new position in the playlist.
+    ///
+    /// Unlike `OAuthClient::playlist_reorder_items`, which is retried like other `PUT` requests,
+    /// the request is only retried when it's rate limited, because retrying a move
+    /// that has been applied by the server moves the items again.
+    pub async fn playlist_reorder_items_once(
+        &self,
+        playlist_id: &PlaylistId,
+        range_start: usize,
+        insert_before: usize,
+        range_length: usize,
+        snapshot_id: &str,
+    ) -> ClientResult<PlaylistResult> {
+        let url = format!("playlists/{}/tracks", playlist_id.id());
+        let payload = serde_json::json!({
+            "range_start": range_start,
+            "insert_before": insert_before,
+            "range_length": range_length,
+            "snapshot_id": snapshot_id,
+        });
+        let result = self
+            .with_retry(false, || async {
+                let headers = self.auth_headers().await;
+                self.put(&url, Some(&headers), &payload).await
+            })
+            .await?;
+        Ok(serde_json::from_str(&result)?)
+    }
+
+    /// gets the client's Librespot session
+    pub fn session(&self) -> Option<Session> {
+        self.session.read().clone()
+    }
+
     /// gets a Spotify access token.
     /// The function may retrieve a new token and update the current token
     /// stored inside the client if the old one is expired.
@@ -95,15 +198,53 @@ impl BaseClient for Spotify {
         &self.config
     }
 
+    // Spotify API endpoint requests are overridden to be made with the client's retry mechanism
+
+    async fn endpoint_get(&self, url: &str, payload: &Query<'_>) -> ClientResult<String> {
+        self.with_retry(true, || async move {
+            let headers = self.auth_headers().await;
+            self.get(url, Some(&headers), payload).await
+        })
+        .await
+    }
+
+    async fn endpoint_post(&self, url: &str, payload: &Value) -> ClientResult<String> {
+        self.with_retry(false, || async move {
+            let headers = self.auth_headers().await;
+            self.post(url, Some(&headers), payload).await
+        })
+        .await
+    }
+
+    async fn endpoint_put(&self, url: &str, payload: &Value) -> ClientResult<String> {
+        self.with_retry(true, || async move {
+            let headers = self.auth_headers().await;
+            self.put(url, Some(&headers), payload).await
+        })
+        .await
+    }
+
+    async fn endpoint_delete(&self, url: &str, payload: &Value) -> ClientResult<String> {
+        self.with_retry(true, || async move {
+            let headers = self.auth_headers().await;
+            self.delete(url, Some(&headers), payload).await
+        })
+        .await
+    }
+
     async fn refetch_token(&self) -> ClientResult<Option<Token>> {
-        let session = match self.session {
+        let session = match self.session() {
             None => {
                 tracing::warn!("There is no session inside the spotify client");
                 return Ok(None);
             }
-            Some(ref session) => session,
+            Some(session) => session,
         };
-        match token::get_token(session, &self.client_id).await {
+        if session.is_invalid() {
+            tracing::warn!("The session inside the spotify client is no longer valid");
+            return Ok(None);
+        }
+        match token::get_token(&session, &self.client_id).await {
             Ok(token) => Ok(Some(token)),
             Err(err) => {
                 tracing::error!("Failed to get access token: {err:#}");
@@ -113,6 +254,42 @@ impl BaseClient for Spotify {
     }
 }
 
+/// returns the duration to wait before retrying a failed request or `None` if the request shouldn't be retried
+fn retry_delay(err: &ClientError, attempt: u32, idempotent: bool) -> Option<Duration> {
+    let backoff = INITIAL_BACKOFF * 2_u32.pow(attempt);
+    let err = match err {
+        ClientError::Http(err) => err,
+        _ => return None,
+    };
+
+    match err.as_ref() {
+        HttpError::StatusCode(response) => {
+            let status = response.status();
+            if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                // `Retry-After` header specifies the number of seconds to wait before retrying
+                let retry_after = response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .map(Duration::from_secs);
+                Some(retry_after.unwrap_or(backoff))
+            } else if status.is_server_error() && idempotent {
+                Some(backoff)
+            } else {
+                None
+            }
+        }
+        HttpError::Client(err)
+            if (err.is_connect() || err.is_timeout() || err.is_request()) && idempotent =>
+        {
+            Some(backoff)
+        }
+        HttpError::Client(_) => None,
+    }
+    .filter(|delay| *delay <= MAX_RETRY_DELAY)
+}
+
 /// Implement `OAuthClient` trait for `Spotify` struct
 /// to allow calling methods that get/modify user's data such as
 /// `current_user_playlists`, `playlist_add_items`, etc.
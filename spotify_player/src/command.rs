@@ -34,18 +34,26 @@ pub enum Command {
     SwitchTheme,
     SwitchDevice,
     Search,
+    CycleSearchType,
+    LoadMoreSearchResults,
 
     ShowActionsOnSelectedItem,
     ShowActionsOnCurrentTrack,
+    ToggleMarkTrack,
+    ClearMarkedTracks,
 
     BrowseUserPlaylists,
+    CreatePlaylist,
     BrowseUserFollowedArtists,
     BrowseUserSavedAlbums,
+    BuildRecommendations,
+    OpenSpotifyLink,
 
     CurrentlyPlayingContextPage,
     TopTrackPage,
     RecentlyPlayedTrackPage,
     LikedTrackPage,
+    QueuePage,
     #[cfg(feature = "lyric-finder")]
     LyricPage,
     LibraryPage,
@@ -58,7 +66,20 @@ pub enum Command {
     SortTrackByAlbum,
     SortTrackByDuration,
     SortTrackByAddedDate,
+    SortTrackByTempo,
+    SortTrackByKey,
+    SortTrackByEnergy,
+    SortTrackByDanceability,
+    SortTrackByValence,
     ReverseTrackOrder,
+    SortAlbumByReleaseDate,
+
+    MovePlaylistTrackUp,
+    MovePlaylistTrackDown,
+    MovePlaylistTrackToPosition,
+
+    RefreshRecommendations,
+    SaveTracksAsPlaylist,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -66,11 +87,13 @@ pub enum TrackAction {
     BrowseArtist,
     BrowseAlbum,
     BrowseRecommendations,
+    AddToRecommendationSeeds,
     AddToQueue,
     AddToPlaylist,
     DeleteFromCurrentPlaylist,
     AddToLikedTracks,
     DeleteFromLikedTracks,
+    CopyLink,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -78,19 +101,45 @@ pub enum AlbumAction {
     BrowseArtist,
     AddToLibrary,
     DeleteFromLibrary,
+    CopyLink,
 }
 
 #[derive(Debug, Copy, Clone)]
 pub enum ArtistAction {
     BrowseRecommendations,
+    AddToRecommendationSeeds,
     Follow,
     Unfollow,
+    CopyLink,
 }
 
 #[derive(Debug, Copy, Clone)]
 pub enum PlaylistAction {
     AddToLibrary,
     DeleteFromLibrary,
+    Edit,
+    Delete,
+    CopyLink,
+}
+
+/// constructs a list of actions on multiple (marked) tracks
+pub fn construct_batch_track_actions(tracks: &[Track], data: &DataReadGuard) -> Vec<TrackAction> {
+    let mut actions = vec![
+        TrackAction::AddToPlaylist,
+        TrackAction::AddToQueue,
+        TrackAction::AddToRecommendationSeeds,
+    ];
+    let (liked, unliked): (Vec<_>, Vec<_>) = tracks
+        .iter()
+        .partition(|track| data.user_data.saved_tracks.iter().any(|t| t.id == track.id));
+    if !unliked.is_empty() {
+        actions.push(TrackAction::AddToLikedTracks);
+    }
+    if !liked.is_empty() {
+        actions.push(TrackAction::DeleteFromLikedTracks);
+    }
+    actions.push(TrackAction::CopyLink);
+    actions
 }
 
 /// constructs a default list of actions on a track
@@ -99,6 +148,7 @@ pub fn construct_track_actions(track: &Track, data: &DataReadGuard) -> Vec<Track
         TrackAction::BrowseArtist,
         TrackAction::BrowseAlbum,
         TrackAction::BrowseRecommendations,
+        TrackAction::AddToRecommendationSeeds,
         TrackAction::AddToPlaylist,
         TrackAction::AddToQueue,
     ];
@@ -107,6 +157,7 @@ pub fn construct_track_actions(track: &Track, data: &DataReadGuard) -> Vec<Track
     } else {
         actions.push(TrackAction::AddToLikedTracks);
     }
+    actions.push(TrackAction::CopyLink);
     actions
 }
 
@@ -136,18 +187,30 @@ impl Command {
             Self::RefreshPlayback => "manually refresh the current playback",
             Self::ShowActionsOnSelectedItem => "open a popup showing actions on a selected item",
             Self::ShowActionsOnCurrentTrack => "open a popup showing actions on the current track",
+            Self::ToggleMarkTrack => {
+                "mark/unmark the selected track for actions on multiple tracks"
+            }
+            Self::ClearMarkedTracks => "unmark all the marked tracks",
             Self::FocusNextWindow => "focus the next focusable window (if any)",
             Self::FocusPreviousWindow => "focus the previous focusable window (if any)",
             Self::SwitchTheme => "open a popup for switching theme",
             Self::SwitchDevice => "open a popup for switching device",
             Self::Search => "open a popup for searching in the current page",
+            Self::CycleSearchType => "cycle the type of search results shown in the search page",
+            Self::LoadMoreSearchResults => "load more results into the focused search page window",
             Self::BrowseUserPlaylists => "open a popup for browsing user's playlists",
+            Self::CreatePlaylist => "open a popup for creating a new playlist",
             Self::BrowseUserFollowedArtists => "open a popup for browsing user's followed artists",
             Self::BrowseUserSavedAlbums => "open a popup for browsing user's saved albums",
+            Self::OpenSpotifyLink => "open a popup for opening a Spotify URL or URI",
+            Self::BuildRecommendations => {
+                "open a popup for building recommendations from seeds and tuned track attributes"
+            }
             Self::CurrentlyPlayingContextPage => "go to the currently playing context page",
             Self::TopTrackPage => "go to the user top track page",
             Self::RecentlyPlayedTrackPage => "go to the user recently played track page",
             Self::LikedTrackPage => "go to the user liked track page",
+            Self::QueuePage => "go to the playback's queue page",
             #[cfg(feature = "lyric-finder")]
             Self::LyricPage => "go to the lyric page of the current track",
             Self::LibraryPage => "go to the user libary page",
@@ -159,7 +222,26 @@ impl Command {
             Self::SortTrackByAlbum => "sort the track table (if any) by track's album",
             Self::SortTrackByDuration => "sort the track table (if any) by track's duration",
             Self::SortTrackByAddedDate => "sort the track table (if any) by track's added date",
+            Self::SortTrackByTempo => "sort the track table (if any) by track's tempo (BPM)",
+            Self::SortTrackByKey => "sort the track table (if any) by track's key",
+            Self::SortTrackByEnergy => "sort the track table (if any) by track's energy",
+            Self::SortTrackByDanceability => {
+                "sort the track table (if any) by track's danceability"
+            }
+            Self::SortTrackByValence => "sort the track table (if any) by track's valence",
             Self::ReverseTrackOrder => "reverse the order of the track table (if any)",
+            Self::SortAlbumByReleaseDate => {
+                "sort the focused album list of an artist page by release date"
+            }
+            Self::MovePlaylistTrackUp => "move the selected track up in the current playlist",
+            Self::MovePlaylistTrackDown => "move the selected track down in the current playlist",
+            Self::MovePlaylistTrackToPosition => {
+                "open a popup for moving the selected track to a position in the current playlist"
+            }
+            Self::RefreshRecommendations => "refresh the current recommendation tracks page",
+            Self::SaveTracksAsPlaylist => {
+                "open a popup for saving the tracks of the current tracks page as a new playlist"
+            }
         }
     }
 }
@@ -75,6 +75,14 @@ impl Default for KeymapConfig {
                     key_sequence: "/".into(),
                     command: Command::Search,
                 },
+                Keymap {
+                    key_sequence: "C-t".into(),
+                    command: Command::CycleSearchType,
+                },
+                Keymap {
+                    key_sequence: "L".into(),
+                    command: Command::LoadMoreSearchResults,
+                },
                 Keymap {
                     key_sequence: "C-space".into(),
                     command: Command::ShowActionsOnSelectedItem,
@@ -87,6 +95,14 @@ impl Default for KeymapConfig {
                     key_sequence: "a".into(),
                     command: Command::ShowActionsOnCurrentTrack,
                 },
+                Keymap {
+                    key_sequence: "v".into(),
+                    command: Command::ToggleMarkTrack,
+                },
+                Keymap {
+                    key_sequence: "V".into(),
+                    command: Command::ClearMarkedTracks,
+                },
                 #[cfg(feature = "streaming")]
                 Keymap {
                     key_sequence: "R".into(),
@@ -112,6 +128,10 @@ impl Default for KeymapConfig {
                     key_sequence: "u p".into(),
                     command: Command::BrowseUserPlaylists,
                 },
+                Keymap {
+                    key_sequence: "N".into(),
+                    command: Command::CreatePlaylist,
+                },
                 Keymap {
                     key_sequence: "u a".into(),
                     command: Command::BrowseUserFollowedArtists,
@@ -120,6 +140,14 @@ impl Default for KeymapConfig {
                     key_sequence: "u A".into(),
                     command: Command::BrowseUserSavedAlbums,
                 },
+                Keymap {
+                    key_sequence: "g R".into(),
+                    command: Command::BuildRecommendations,
+                },
+                Keymap {
+                    key_sequence: "g o".into(),
+                    command: Command::OpenSpotifyLink,
+                },
                 Keymap {
                     key_sequence: "g space".into(),
                     command: Command::CurrentlyPlayingContextPage,
@@ -136,6 +164,10 @@ impl Default for KeymapConfig {
                     key_sequence: "g y".into(),
                     command: Command::LikedTrackPage,
                 },
+                Keymap {
+                    key_sequence: "z".into(),
+                    command: Command::QueuePage,
+                },
                 #[cfg(feature = "lyric-finder")]
                 Keymap {
                     key_sequence: "g L".into(),
@@ -230,10 +262,54 @@ impl Default for KeymapConfig {
                     key_sequence: "s D".into(),
                     command: Command::SortTrackByAddedDate,
                 },
+                Keymap {
+                    key_sequence: "s b".into(),
+                    command: Command::SortTrackByTempo,
+                },
+                Keymap {
+                    key_sequence: "s k".into(),
+                    command: Command::SortTrackByKey,
+                },
+                Keymap {
+                    key_sequence: "s e".into(),
+                    command: Command::SortTrackByEnergy,
+                },
+                Keymap {
+                    key_sequence: "s n".into(),
+                    command: Command::SortTrackByDanceability,
+                },
+                Keymap {
+                    key_sequence: "s v".into(),
+                    command: Command::SortTrackByValence,
+                },
                 Keymap {
                     key_sequence: "s r".into(),
                     command: Command::ReverseTrackOrder,
                 },
+                Keymap {
+                    key_sequence: "s y".into(),
+                    command: Command::SortAlbumByReleaseDate,
+                },
+                Keymap {
+                    key_sequence: "K".into(),
+                    command: Command::MovePlaylistTrackUp,
+                },
+                Keymap {
+                    key_sequence: "J".into(),
+                    command: Command::MovePlaylistTrackDown,
+                },
+                Keymap {
+                    key_sequence: "M".into(),
+                    command: Command::MovePlaylistTrackToPosition,
+                },
+                Keymap {
+                    key_sequence: "F".into(),
+                    command: Command::RefreshRecommendations,
+                },
+                Keymap {
+                    key_sequence: "S".into(),
+                    command: Command::SaveTracksAsPlaylist,
+                },
             ],
         }
     }
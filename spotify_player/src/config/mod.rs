@@ -34,11 +34,20 @@ pub struct AppConfig {
     pub cover_image_refresh_duration_in_ms: u64,
 
     pub track_table_item_max_len: usize,
+    pub track_table_audio_features: bool,
+
+    pub autoplay: bool,
 
     // icon configs
     pub play_icon: String,
     pub pause_icon: String,
 
+    // format template of the playback printed by the `get playback` CLI subcommand
+    pub playback_format: String,
+
+    // format template of an item's link copied by the `CopyLink` action
+    pub share_link_format: String,
+
     // layout configs
     #[cfg(feature = "image")]
     pub cover_img_length: usize,
@@ -52,6 +61,19 @@ pub struct AppConfig {
     pub default_device: String,
 
     pub device: DeviceConfig,
+
+    pub hooks: HooksConfig,
+}
+
+#[derive(Debug, Deserialize, ConfigParse, Clone, Default)]
+/// Application hook configurations, each of which is a shell command
+/// run when the corresponding player event happens
+pub struct HooksConfig {
+    pub track_change: Option<String>,
+    pub playback_pause: Option<String>,
+    pub playback_resume: Option<String>,
+    pub device_change: Option<String>,
+    pub context_change: Option<String>,
 }
 
 #[derive(Debug, Deserialize, ConfigParse, Clone)]
@@ -62,6 +84,31 @@ pub struct DeviceConfig {
     pub volume: u8,
     pub bitrate: u16,
     pub audio_cache: bool,
+    /// the audio cache's maximum size in MB, `None` for no limit
+    pub audio_cache_size_limit_in_mb: Option<u64>,
+    /// the number of the playback queue's next tracks to pre-fetch into the audio cache
+    pub prefetch_tracks: usize,
+    /// the audio backend's name, `None` to use the default (first compiled-in) backend
+    pub audio_backend: Option<String>,
+    /// the audio backend's output device, `None` to use the system's default device
+    pub audio_device: Option<String>,
+    pub audio_format: String,
+    pub gapless: bool,
+    pub normalisation: bool,
+    pub normalisation_type: String,
+    /// the normalisation's pregain in dB
+    pub normalisation_pregain: f64,
+    /// the normalisation's threshold in dBFS
+    pub normalisation_threshold: f64,
+    /// the normalisation's attack time in milliseconds
+    pub normalisation_attack: u64,
+    /// the normalisation's release time in milliseconds
+    pub normalisation_release: u64,
+    pub volume_ctrl: String,
+    pub mixer: String,
+    /// the mixer's device and control, only used by the `alsa` mixer
+    pub mixer_device: String,
+    pub mixer_control: String,
 }
 
 impl Default for AppConfig {
@@ -79,10 +126,18 @@ impl Default for AppConfig {
             cover_image_refresh_duration_in_ms: 2000,
 
             track_table_item_max_len: 32,
+            track_table_audio_features: false,
+
+            autoplay: false,
 
             play_icon: "⏸".to_string(),
             pause_icon: "▶".to_string(),
 
+            playback_format: "{status} {track} • {artists} ▎ {album} [{progress}/{duration}]"
+                .to_string(),
+
+            share_link_format: "{url}".to_string(),
+
             #[cfg(feature = "image")]
             cover_img_length: 9,
             #[cfg(feature = "image")]
@@ -104,6 +159,8 @@ impl Default for AppConfig {
             default_device: "spotify-player".to_string(),
 
             device: DeviceConfig::default(),
+
+            hooks: HooksConfig::default(),
         }
     }
 }
@@ -116,6 +173,22 @@ impl Default for DeviceConfig {
             volume: 50,
             bitrate: 160,
             audio_cache: false,
+            audio_cache_size_limit_in_mb: None,
+            prefetch_tracks: 0,
+            audio_backend: None,
+            audio_device: None,
+            audio_format: "S16".to_string(),
+            gapless: true,
+            normalisation: false,
+            normalisation_type: "auto".to_string(),
+            normalisation_pregain: 0.0,
+            normalisation_threshold: -2.0,
+            normalisation_attack: 5,
+            normalisation_release: 100,
+            volume_ctrl: "log".to_string(),
+            mixer: "softvol".to_string(),
+            mixer_device: "default".to_string(),
+            mixer_control: "PCM".to_string(),
         }
     }
 }
@@ -55,6 +55,8 @@ pub struct ComponentStyle {
     pub playback_progress_bar: Style,
 
     pub current_playing: Style,
+    #[serde(default = "default_marked_track_style")]
+    pub marked_track: Style,
 
     pub page_desc: Style,
     pub table_header: Style,
@@ -189,6 +191,7 @@ impl Theme {
         playback_metadata,
         playback_progress_bar,
         current_playing,
+        marked_track,
         page_desc,
         table_header
     );
@@ -449,6 +452,7 @@ impl Default for ComponentStyle {
             current_playing: Style::default()
                 .fg(StyleColor::Green)
                 .modifiers(vec![StyleModifier::Bold]),
+            marked_track: default_marked_track_style(),
 
             page_desc: Style::default()
                 .fg(StyleColor::Cyan)
@@ -457,3 +461,9 @@ impl Default for ComponentStyle {
         }
     }
 }
+
+fn default_marked_track_style() -> Style {
+    Style::default()
+        .fg(StyleColor::Yellow)
+        .modifiers(vec![StyleModifier::Italic])
+}
@@ -19,11 +19,15 @@ mod window;
 pub enum PlayerRequest {
     NextTrack,
     PreviousTrack,
+    Resume,
+    Pause,
     ResumePause,
     SeekTrack(u32),
     Repeat,
     Shuffle,
     Volume(u8),
+    /// skips the next `n` tracks of the playback's queue
+    SkipTracks(usize),
     TransferPlayback(String, bool),
     StartPlayback(Playback),
 }
@@ -35,21 +39,40 @@ pub enum ClientRequest {
     GetDevices,
     GetBrowseCategories,
     GetBrowseCategoryPlaylists(Category),
+    GetUserPublicPlaylists(UserId),
     GetUserPlaylists,
     GetUserSavedAlbums,
     GetUserFollowedArtists,
     GetUserSavedTracks,
+    GetUserSavedShows,
     GetUserTopTracks,
     GetUserRecentlyPlayedTracks,
     GetContext(ContextId),
+    /// gets audio features of a context's tracks, then sorts the context's tracks
+    /// by the order (if any) stored in `Caches::loading_audio_features`
+    GetContextAudioFeatures(ContextId),
     GetCurrentPlayback,
-    GetRecommendations(SeedItem),
+    GetCurrentUserQueue,
+    GetRecommendations(RecommendationQuery),
+    /// starts a playback of tracks recommended from the recently played tracks
+    Autoplay(Vec<Track>),
     Search(String),
-    AddTrackToQueue(TrackId),
-    AddTrackToPlaylist(PlaylistId, TrackId),
-    DeleteTrackFromPlaylist(PlaylistId, TrackId),
+    /// loads more results of a search query of a specific type
+    SearchMore(String, SearchType),
+    AddToSearchHistory(String),
+    AddTracksToQueue(Vec<TrackId>),
+    AddTracksToPlaylist(PlaylistId, Vec<TrackId>),
+    DeleteTracksFromPlaylist(PlaylistId, Vec<TrackId>),
+    /// moves a playlist's track from a position to another position,
+    /// the move is applied to the cached playlist's tracks before sending the request
+    MovePlaylistTrack(PlaylistId, usize, usize),
     AddToLibrary(Item),
     DeleteFromLibrary(ItemId),
+    AddTracksToLibrary(Vec<Track>),
+    DeleteTracksFromLibrary(Vec<TrackId>),
+    /// creates a new playlist, then adds tracks (if any) to the playlist
+    CreatePlaylist(PlaylistDetails, Vec<TrackId>),
+    EditPlaylist(PlaylistId, PlaylistDetails),
     ConnectDevice(Option<String>),
     Player(PlayerRequest),
     #[cfg(feature = "lyric-finder")]
@@ -59,6 +82,8 @@ pub enum ClientRequest {
     },
     #[cfg(feature = "streaming")]
     NewStreamingConnection,
+    /// A request whose handling result is sent back to the sender via a response channel
+    WithResponse(Box<ClientRequest>, flume::Sender<Result<()>>),
 }
 
 /// starts a terminal event handler (key pressed, mouse clicked, etc)
@@ -89,11 +114,7 @@ fn handle_mouse_event(
         if event.row == rect.y {
             // calculate the seek position (in ms) based on the mouse click position,
             // the progress bar's width and the track's duration (in ms)
-            let duration = state
-                .player
-                .read()
-                .current_playing_track()
-                .map(|t| t.duration);
+            let duration = state.player.read().current_playing_item_duration();
             if let Some(duration) = duration {
                 let position_ms =
                     (duration.as_millis() as u32) * (event.column as u32) / (rect.width as u32);
@@ -139,6 +160,9 @@ fn handle_key_event(
             PageType::Tracks => {
                 page::handle_key_sequence_for_tracks_page(&key_sequence, client_pub, state)?
             }
+            PageType::Queue => {
+                page::handle_key_sequence_for_queue_page(&key_sequence, client_pub, state)?
+            }
             PageType::Browse => {
                 page::handle_key_sequence_for_browse_page(&key_sequence, client_pub, state)?
             }
@@ -265,6 +289,18 @@ fn handle_global_command(
                 new_list_state(),
             ));
         }
+        Command::CreatePlaylist => {
+            ui.popup = Some(PopupState::PlaylistEditor(PlaylistEditorState::new()));
+        }
+        Command::OpenSpotifyLink => {
+            ui.popup = Some(PopupState::OpenLink {
+                link: String::new(),
+            });
+        }
+        Command::BuildRecommendations => {
+            ui.recommendations_builder.focus = RecommendationsBuilderField::Seeds;
+            ui.popup = Some(PopupState::RecommendationsBuilder);
+        }
         Command::BrowseUserFollowedArtists => {
             client_pub.send(ClientRequest::GetUserFollowedArtists)?;
             ui.popup = Some(PopupState::UserFollowedArtistList(new_list_state()));
@@ -278,6 +314,7 @@ fn handle_global_command(
                 id: "top-tracks".to_string(),
                 title: "Top Tracks".to_string(),
                 desc: "User's top tracks".to_string(),
+                query: None,
                 state: new_table_state(),
             });
             client_pub.send(ClientRequest::GetUserTopTracks)?;
@@ -287,15 +324,23 @@ fn handle_global_command(
                 id: "recently-played-tracks".to_string(),
                 title: "Recently Played Tracks".to_string(),
                 desc: "User's recently played tracks".to_string(),
+                query: None,
                 state: new_table_state(),
             });
             client_pub.send(ClientRequest::GetUserRecentlyPlayedTracks)?;
         }
+        Command::QueuePage => {
+            ui.create_new_page(PageState::Queue {
+                state: new_table_state(),
+            });
+            client_pub.send(ClientRequest::GetCurrentUserQueue)?;
+        }
         Command::LikedTrackPage => {
             ui.create_new_page(PageState::Tracks {
                 id: "liked-tracks".to_string(),
                 title: "Liked Tracks".to_string(),
                 desc: "User's liked tracks".to_string(),
+                query: None,
                 state: new_table_state(),
             });
         }
@@ -319,10 +364,18 @@ fn handle_global_command(
             });
             client_pub.send(ClientRequest::GetBrowseCategories)?;
         }
+        Command::ClearMarkedTracks => {
+            ui.marked_tracks.clear();
+        }
         Command::PreviousPage => {
             if ui.history.len() > 1 {
                 ui.history.pop();
                 ui.popup = None;
+                ui.marked_tracks.clear();
+                // the queue may change while the queue page is not shown
+                if matches!(ui.current_page(), PageState::Queue { .. }) {
+                    client_pub.send(ClientRequest::GetCurrentUserQueue)?;
+                }
             }
         }
         #[cfg(feature = "lyric-finder")]
@@ -374,3 +427,36 @@ fn handle_global_command(
     }
     Ok(true)
 }
+
+/// opens a Spotify item referred by a Spotify URL or URI by either
+/// starting a playback of the item (track) or browsing the item's page
+pub fn open_spotify_link(
+    link: SpotifyLink,
+    client_pub: &flume::Sender<ClientRequest>,
+    ui: &mut UIStateGuard,
+) -> Result<()> {
+    match link {
+        SpotifyLink::Track(id) => {
+            client_pub.send(ClientRequest::Player(PlayerRequest::StartPlayback(
+                Playback::URIs(vec![id], None),
+            )))?;
+        }
+        SpotifyLink::Context(id) => {
+            ui.create_new_page(PageState::Context {
+                id: None,
+                context_page_type: ContextPageType::Browsing(id),
+                state: None,
+            });
+        }
+        SpotifyLink::User(id) => {
+            client_pub.send(ClientRequest::GetUserPublicPlaylists(id.clone()))?;
+            ui.create_new_page(PageState::Browse {
+                state: BrowsePageUIState::UserPlaylists {
+                    user_id: id,
+                    state: new_list_state(),
+                },
+            });
+        }
+    }
+    Ok(())
+}
@@ -51,6 +51,11 @@ pub fn handle_key_sequence_for_library_page(
                         ui,
                     )
                 }
+                LibraryFocusState::SavedShows => window::handle_command_for_show_list_window(
+                    command,
+                    ui.search_filtered_items(&data.user_data.saved_shows),
+                    ui,
+                ),
             }
         }
     }
@@ -63,14 +68,15 @@ pub fn handle_key_sequence_for_search_page(
 ) -> Result<bool> {
     let mut ui = state.ui.lock();
 
-    let (focus_state, input, current_query) = match ui.current_page_mut() {
+    let (page_state, input, current_query) = match ui.current_page_mut() {
         PageState::Search {
             state,
             input,
             current_query,
-        } => (state.focus, input, current_query),
+        } => (state, input, current_query),
         _ => anyhow::bail!("expect a search page"),
     };
+    let focus_state = page_state.focus;
 
     // handle user's input
     if let SearchFocusState::Input = focus_state {
@@ -90,7 +96,33 @@ pub fn handle_key_sequence_for_search_page(
                     crossterm::event::KeyCode::Enter => {
                         if !input.is_empty() {
                             *current_query = input.clone();
+                            page_state.history_index = None;
                             client_pub.send(ClientRequest::Search(input.clone()))?;
+                            client_pub.send(ClientRequest::AddToSearchHistory(input.clone()))?;
+                        }
+                        return Ok(true);
+                    }
+                    // recall a query from the search history, `up` for an older query
+                    // and `down` for a more recent query
+                    crossterm::event::KeyCode::Up | crossterm::event::KeyCode::Down => {
+                        let data = state.data.read();
+                        let history = &data.user_data.search_history;
+                        let index = match (c, page_state.history_index) {
+                            (crossterm::event::KeyCode::Up, None) => Some(0),
+                            (crossterm::event::KeyCode::Up, Some(i)) => Some(i + 1),
+                            (_, Some(i)) if i > 0 => Some(i - 1),
+                            _ => None,
+                        };
+                        match index {
+                            Some(i) if i < history.len() => {
+                                *input = history[i].clone();
+                                page_state.history_index = Some(i);
+                            }
+                            Some(_) => {}
+                            None => {
+                                input.clear();
+                                page_state.history_index = None;
+                            }
                         }
                         return Ok(true);
                     }
@@ -98,7 +130,6 @@ pub fn handle_key_sequence_for_search_page(
                 }
             }
         }
-        return Ok(false);
     }
 
     let command = match state
@@ -109,6 +140,23 @@ pub fn handle_key_sequence_for_search_page(
         None => return Ok(false),
     };
 
+    if let Command::CycleSearchType = command {
+        page_state.cycle_search_type();
+        return Ok(true);
+    }
+    if let SearchFocusState::Input = focus_state {
+        return Ok(false);
+    }
+    if let Command::LoadMoreSearchResults = command {
+        if let Some(search_type) = focus_state.search_type() {
+            client_pub.send(ClientRequest::SearchMore(
+                current_query.clone(),
+                search_type,
+            ))?;
+        }
+        return Ok(true);
+    }
+
     let data = state.data.read();
     let search_results = data.caches.search.peek(current_query);
 
@@ -177,6 +225,14 @@ pub fn handle_key_sequence_for_context_page(
                     let offset = match context {
                         // Spotify does not allow to manually specify `offset` for artist context
                         Context::Artist { .. } => None,
+                        Context::Show { episodes, .. } => {
+                            if episodes.is_empty() {
+                                None
+                            } else {
+                                let id = rand::thread_rng().gen_range(0..episodes.len());
+                                Some(rspotify_model::Offset::for_uri(&episodes[id].id.uri()))
+                            }
+                        }
                         _ => {
                             if tracks.is_empty() {
                                 None
@@ -193,6 +249,97 @@ pub fn handle_key_sequence_for_context_page(
                 }
             }
         }
+        Command::SortAlbumByReleaseDate => {
+            let context_id = match context_id {
+                Some(ContextId::Artist(id)) => ContextId::Artist(id),
+                _ => return Ok(false),
+            };
+
+            let mut ui = state.ui.lock();
+            let focus = match ui.current_page() {
+                PageState::Context {
+                    state: Some(ContextPageUIState::Artist { focus, .. }),
+                    ..
+                } => *focus,
+                _ => return Ok(false),
+            };
+
+            let mut data = state.data.write();
+            if let Some(Context::Artist {
+                albums,
+                singles,
+                compilations,
+                appears_on,
+                ..
+            }) = data.caches.context.peek_mut(&context_id.uri())
+            {
+                let albums = match focus {
+                    ArtistFocusState::Albums => albums,
+                    ArtistFocusState::Singles => singles,
+                    ArtistFocusState::Compilations => compilations,
+                    ArtistFocusState::AppearsOn => appears_on,
+                    _ => return Ok(false),
+                };
+
+                // toggle between the newest-first and the oldest-first order
+                let newest_first = albums.first().map(|a| &a.release_date)
+                    >= albums.last().map(|a| &a.release_date);
+                if newest_first {
+                    albums.sort_by(|x, y| x.release_date.cmp(&y.release_date));
+                } else {
+                    albums.sort_by(|x, y| y.release_date.cmp(&x.release_date));
+                }
+                ui.current_page_mut().select(0);
+            }
+        }
+        Command::MovePlaylistTrackUp
+        | Command::MovePlaylistTrackDown
+        | Command::MovePlaylistTrackToPosition => {
+            let playlist_id = match context_id {
+                Some(ContextId::Playlist(id)) => id,
+                _ => return Ok(false),
+            };
+
+            let mut ui = state.ui.lock();
+            // the track table's positions don't match the playlist's track positions
+            // if the table is filtered by a search query
+            if ui.popup.is_some() {
+                return Ok(false);
+            }
+            let id = match ui.current_page_mut().selected() {
+                Some(id) => id,
+                None => return Ok(false),
+            };
+
+            match command {
+                Command::MovePlaylistTrackUp => {
+                    if id > 0
+                        && move_playlist_track(
+                            state,
+                            client_pub,
+                            &mut ui,
+                            &playlist_id,
+                            id,
+                            id - 1,
+                        )?
+                    {
+                        ui.current_page_mut().select(id - 1);
+                    }
+                }
+                Command::MovePlaylistTrackDown => {
+                    if move_playlist_track(state, client_pub, &mut ui, &playlist_id, id, id + 1)? {
+                        ui.current_page_mut().select(id + 1);
+                    }
+                }
+                _ => {
+                    ui.popup = Some(PopupState::PlaylistTrackPosition {
+                        playlist_id,
+                        track_index: id,
+                        position: String::new(),
+                    });
+                }
+            }
+        }
         _ => {
             // handle sort/reverse tracks commands
             let order = match command {
@@ -201,14 +348,43 @@ pub fn handle_key_sequence_for_context_page(
                 Command::SortTrackByArtists => Some(TrackOrder::Artists),
                 Command::SortTrackByAddedDate => Some(TrackOrder::AddedAt),
                 Command::SortTrackByDuration => Some(TrackOrder::Duration),
+                Command::SortTrackByTempo => Some(TrackOrder::Tempo),
+                Command::SortTrackByKey => Some(TrackOrder::Key),
+                Command::SortTrackByEnergy => Some(TrackOrder::Energy),
+                Command::SortTrackByDanceability => Some(TrackOrder::Danceability),
+                Command::SortTrackByValence => Some(TrackOrder::Valence),
                 _ => None,
             };
 
             if let Some(order) = order {
                 if let Some(context_id) = context_id {
+                    let uri = context_id.uri();
                     let mut data = state.data.write();
-                    if let Some(context) = data.caches.context.peek_mut(&context_id.uri()) {
+                    if order.requires_audio_features() {
+                        if data.caches.audio_features_contexts.insert(uri.clone()) {
+                            // the context's tracks are sorted after getting their audio features
+                            data.caches.loading_audio_features.insert(uri, Some(order));
+                            client_pub.send(ClientRequest::GetContextAudioFeatures(context_id))?;
+                            return Ok(true);
+                        }
+                        if let Some(pending_order) =
+                            data.caches.loading_audio_features.get_mut(&uri)
+                        {
+                            // the audio features are being loaded, queue the order to sort
+                            // the tracks once the audio features are loaded
+                            *pending_order = Some(order);
+                            return Ok(true);
+                        }
+                    } else if let Some(pending_order) =
+                        data.caches.loading_audio_features.get_mut(&uri)
+                    {
+                        // the new order replaces the queued order
+                        *pending_order = None;
+                    }
+
+                    if let Some(context) = data.caches.context.peek_mut(&uri) {
                         context.sort_tracks(order);
+                        data.caches.sorted_contexts.insert(uri);
                     }
                 }
                 return Ok(true);
@@ -218,6 +394,7 @@ pub fn handle_key_sequence_for_context_page(
                     let mut data = state.data.write();
                     if let Some(context) = data.caches.context.peek_mut(&context_id.uri()) {
                         context.reverse_tracks();
+                        data.caches.sorted_contexts.insert(context_id.uri());
                     }
                 }
                 return Ok(true);
@@ -230,6 +407,73 @@ pub fn handle_key_sequence_for_context_page(
     Ok(true)
 }
 
+/// moves the track at position `from` of a playlist to position `to`.
+/// The move is applied to the cached playlist's tracks right away,
+/// then a request is sent to the client to move the track in Spotify.
+///
+/// Returns `true` if the track is moved.
+pub fn move_playlist_track(
+    state: &SharedState,
+    client_pub: &flume::Sender<ClientRequest>,
+    ui: &mut UIStateGuard,
+    playlist_id: &PlaylistId,
+    from: usize,
+    to: usize,
+) -> Result<bool> {
+    let mut data = state.data.write();
+    if !data
+        .user_data
+        .modifiable_playlists()
+        .iter()
+        .any(|p| p.id == *playlist_id)
+    {
+        return Ok(false);
+    }
+
+    let uri = playlist_id.uri();
+    if data.caches.incomplete_contexts.contains(&uri) {
+        return Ok(false);
+    }
+    if data.caches.filtered_contexts.contains(&uri) {
+        tracing::warn!(
+            "Cannot move tracks of the playlist {playlist_id} containing local files or episodes"
+        );
+        return Ok(false);
+    }
+    if data.caches.sorted_contexts.remove(&uri) {
+        // Reload the playlist to get the playlist's tracks in the original order.
+        // The selected track's position changes after reloading, so the move isn't applied.
+        tracing::info!("Reloading the sorted playlist {playlist_id} before moving its tracks");
+        data.caches.context.pop(&uri);
+        client_pub.send(ClientRequest::GetContext(ContextId::Playlist(
+            playlist_id.clone(),
+        )))?;
+        ui.popup = Some(PopupState::Message(
+            "The sort order is reset to move tracks, retry after the playlist is reloaded"
+                .to_string(),
+        ));
+        return Ok(false);
+    }
+
+    match data.caches.context.peek_mut(&uri) {
+        Some(context) => {
+            let n_tracks = context.tracks().len();
+            if from == to || from >= n_tracks || to >= n_tracks {
+                return Ok(false);
+            }
+            context.move_track(from, to);
+        }
+        None => return Ok(false),
+    }
+
+    client_pub.send(ClientRequest::MovePlaylistTrack(
+        playlist_id.clone(),
+        from,
+        to,
+    ))?;
+    Ok(true)
+}
+
 pub fn handle_key_sequence_for_tracks_page(
     key_sequence: &KeySequence,
     client_pub: &flume::Sender<ClientRequest>,
@@ -246,17 +490,40 @@ pub fn handle_key_sequence_for_tracks_page(
     let mut ui = state.ui.lock();
     let data = state.data.read();
 
-    let id = match ui.current_page() {
-        PageState::Tracks { id, .. } => id,
+    let (id, desc, query) = match ui.current_page() {
+        PageState::Tracks {
+            id, desc, query, ..
+        } => (id.clone(), desc.clone(), query.clone()),
         _ => anyhow::bail!("expect a tracks page"),
     };
 
     let tracks = data
-        .get_tracks_by_id(id)
+        .get_tracks_by_id(&id)
         .map(|tracks| ui.search_filtered_items(tracks))
         .unwrap_or_default();
 
     match command {
+        Command::RefreshRecommendations => {
+            let query = match query {
+                Some(query) => query,
+                None => return Ok(false),
+            };
+            // remove the cached recommendation tracks to force refetching new tracks
+            drop(data);
+            state.data.write().caches.tracks.pop(&id);
+            client_pub.send(ClientRequest::GetRecommendations(query))?;
+            ui.current_page_mut().select(0);
+            Ok(true)
+        }
+        Command::SaveTracksAsPlaylist => {
+            let track_ids = tracks.iter().map(|t| t.id.clone()).collect::<Vec<_>>();
+            if !track_ids.is_empty() {
+                ui.popup = Some(PopupState::PlaylistEditor(
+                    PlaylistEditorState::with_tracks(desc, track_ids),
+                ));
+            }
+            Ok(true)
+        }
         Command::Search => {
             ui.current_page_mut().select(0);
             ui.popup = Some(PopupState::Search {
@@ -288,6 +555,45 @@ pub fn handle_key_sequence_for_tracks_page(
     }
 }
 
+pub fn handle_key_sequence_for_queue_page(
+    key_sequence: &KeySequence,
+    client_pub: &flume::Sender<ClientRequest>,
+    state: &SharedState,
+) -> Result<bool> {
+    let command = match state
+        .keymap_config
+        .find_command_from_key_sequence(key_sequence)
+    {
+        Some(command) => command,
+        None => return Ok(false),
+    };
+
+    let mut ui = state.ui.lock();
+    let data = state.data.read();
+    let player = state.player.read();
+
+    let queue = match player.queue {
+        Some(ref queue) => queue,
+        None => return Ok(false),
+    };
+    let tracks = queue.tracks();
+
+    match command {
+        Command::ChooseSelected => {
+            // jump to the selected track by skipping all tracks before it in the queue
+            let id = ui.current_page_mut().selected().unwrap_or_default();
+            if let Some(n_skips) = queue.skips_to_track(id).filter(|n| *n > 0) {
+                client_pub.send(ClientRequest::Player(PlayerRequest::SkipTracks(n_skips)))?;
+                ui.current_page_mut().select(0);
+            }
+            Ok(true)
+        }
+        _ => window::handle_command_for_track_table_window(
+            command, client_pub, None, None, tracks, &data, ui,
+        ),
+    }
+}
+
 pub fn handle_key_sequence_for_browse_page(
     key_sequence: &KeySequence,
     client_pub: &flume::Sender<ClientRequest>,
@@ -315,6 +621,12 @@ pub fn handle_key_sequence_for_browse_page(
                 .get(&category.id)
                 .map(|v| ui.search_filtered_items(v).len())
                 .unwrap_or_default(),
+            BrowsePageUIState::UserPlaylists { user_id, .. } => data
+                .browse
+                .user_playlists
+                .get(user_id.id())
+                .map(|v| ui.search_filtered_items(v).len())
+                .unwrap_or_default(),
         },
         _ => anyhow::bail!("expect a browse page state"),
     };
@@ -358,6 +670,21 @@ pub fn handle_key_sequence_for_browse_page(
                             state: None,
                         });
                     }
+                    BrowsePageUIState::UserPlaylists { user_id, .. } => {
+                        let playlists = data
+                            .browse
+                            .user_playlists
+                            .get(user_id.id())
+                            .context(format!("expect to have playlists data for {user_id} user"))?;
+                        let context_id = ContextId::Playlist(
+                            ui.search_filtered_items(playlists)[selected].id.clone(),
+                        );
+                        ui.create_new_page(PageState::Context {
+                            id: None,
+                            context_page_type: ContextPageType::Browsing(context_id),
+                            state: None,
+                        });
+                    }
                 },
                 _ => anyhow::bail!("expect a browse page state"),
             };
@@ -11,16 +11,45 @@ pub fn handle_key_sequence_for_popup(
     client_pub: &flume::Sender<ClientRequest>,
     state: &SharedState,
 ) -> Result<bool> {
-    let ui = state.ui.lock();
+    let mut ui = state.ui.lock();
     let popup = ui
         .popup
         .as_ref()
         .with_context(|| "expect to exist a popup".to_string())?;
 
+    if let PopupState::Message(_) = popup {
+        ui.popup = None;
+        return Ok(true);
+    }
+
     if let PopupState::Search { .. } = popup {
         drop(ui);
         return handle_key_sequence_for_search_popup(key_sequence, client_pub, state);
     }
+    if let PopupState::PlaylistEditor(_) = popup {
+        drop(ui);
+        return handle_key_sequence_for_playlist_editor_popup(key_sequence, client_pub, state);
+    }
+    if let PopupState::RecommendationsBuilder = popup {
+        drop(ui);
+        return handle_key_sequence_for_recommendations_builder_popup(
+            key_sequence,
+            client_pub,
+            state,
+        );
+    }
+    if let PopupState::OpenLink { .. } = popup {
+        drop(ui);
+        return handle_key_sequence_for_open_link_popup(key_sequence, client_pub, state);
+    }
+    if let PopupState::PlaylistTrackPosition { .. } = popup {
+        drop(ui);
+        return handle_key_sequence_for_playlist_track_position_popup(
+            key_sequence,
+            client_pub,
+            state,
+        );
+    }
 
     let command = match state
         .keymap_config
@@ -31,7 +60,14 @@ pub fn handle_key_sequence_for_popup(
     };
 
     match popup {
-        PopupState::Search { .. } => anyhow::bail!("should be handled before"),
+        PopupState::Search { .. }
+        | PopupState::PlaylistEditor(_)
+        | PopupState::RecommendationsBuilder
+        | PopupState::PlaylistTrackPosition { .. }
+        | PopupState::OpenLink { .. }
+        | PopupState::Message(_) => {
+            anyhow::bail!("should be handled before")
+        }
         PopupState::ArtistList(artists, _) => {
             let n_items = artists.len();
 
@@ -78,8 +114,8 @@ pub fn handle_key_sequence_for_popup(
                     rspotify_model::Type::Playlist,
                 )
             }
-            PlaylistPopupAction::AddTrack(track_id) => {
-                let track_id = track_id.clone();
+            PlaylistPopupAction::AddTracks(track_ids) => {
+                let track_ids = track_ids.clone();
                 let playlist_ids = state
                     .data
                     .read()
@@ -95,11 +131,12 @@ pub fn handle_key_sequence_for_popup(
                     playlist_ids.len(),
                     |_, _| {},
                     |ui: &mut UIStateGuard, id: usize| -> Result<()> {
-                        client_pub.send(ClientRequest::AddTrackToPlaylist(
+                        client_pub.send(ClientRequest::AddTracksToPlaylist(
                             playlist_ids[id].clone(),
-                            track_id.clone(),
+                            track_ids.clone(),
                         ))?;
                         ui.popup = None;
+                        ui.marked_tracks.clear();
                         Ok(())
                     },
                     |ui: &mut UIStateGuard| {
@@ -196,7 +233,7 @@ pub fn handle_key_sequence_for_popup(
         }
         PopupState::CommandHelp { .. } => handle_command_for_command_help_popup(command, ui),
         PopupState::ActionList(item, ..) => {
-            handle_command_for_action_list_popup(item.n_actions(), command, client_pub, ui)
+            handle_command_for_action_list_popup(item.n_actions(), command, client_pub, state, ui)
         }
     }
 }
@@ -257,6 +294,9 @@ fn handle_key_sequence_for_search_popup(
         PageType::Tracks => {
             page::handle_key_sequence_for_tracks_page(key_sequence, client_pub, state)
         }
+        PageType::Queue => {
+            page::handle_key_sequence_for_queue_page(key_sequence, client_pub, state)
+        }
         PageType::Browse => {
             page::handle_key_sequence_for_browse_page(key_sequence, client_pub, state)
         }
@@ -267,6 +307,361 @@ fn handle_key_sequence_for_search_popup(
     }
 }
 
+/// handles a key sequence for a playlist editor popup
+fn handle_key_sequence_for_playlist_editor_popup(
+    key_sequence: &KeySequence,
+    client_pub: &flume::Sender<ClientRequest>,
+    state: &SharedState,
+) -> Result<bool> {
+    let mut ui = state.ui.lock();
+    let editor = match ui.popup {
+        Some(PopupState::PlaylistEditor(ref mut editor)) => editor,
+        _ => return Ok(false),
+    };
+
+    // handle user's input that updates the focused field
+    if key_sequence.keys.len() == 1 {
+        if let Key::None(c) = key_sequence.keys[0] {
+            match (c, editor.focused_text_input_mut()) {
+                (crossterm::event::KeyCode::Char(c), Some(input)) => {
+                    input.push(c);
+                    return Ok(true);
+                }
+                (crossterm::event::KeyCode::Backspace, Some(input)) => {
+                    input.pop();
+                    return Ok(true);
+                }
+                (crossterm::event::KeyCode::Char(' '), None) => {
+                    editor.toggle_focused_field();
+                    return Ok(true);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let command = match state
+        .keymap_config
+        .find_command_from_key_sequence(key_sequence)
+    {
+        Some(command) => command,
+        None => return Ok(false),
+    };
+
+    match command {
+        Command::FocusNextWindow | Command::SelectNextOrScrollDown => editor.focus.next(),
+        Command::FocusPreviousWindow | Command::SelectPreviousOrScrollUp => editor.focus.previous(),
+        Command::ChooseSelected => {
+            if editor.name.trim().is_empty() {
+                // a playlist's name is required
+                editor.focus = PlaylistEditorField::Name;
+                return Ok(true);
+            }
+
+            let details = editor.details();
+            match editor.playlist {
+                None => client_pub.send(ClientRequest::CreatePlaylist(
+                    details,
+                    editor.tracks.clone(),
+                ))?,
+                Some(ref playlist) => {
+                    client_pub.send(ClientRequest::EditPlaylist(playlist.id.clone(), details))?
+                }
+            }
+            ui.popup = None;
+        }
+        Command::ClosePopup => {
+            ui.popup = None;
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+/// handles a key sequence for a recommendations builder popup
+fn handle_key_sequence_for_recommendations_builder_popup(
+    key_sequence: &KeySequence,
+    client_pub: &flume::Sender<ClientRequest>,
+    state: &SharedState,
+) -> Result<bool> {
+    let mut ui = state.ui.lock();
+    let builder = &mut ui.recommendations_builder;
+
+    // handle user's input that updates the focused field
+    if key_sequence.keys.len() == 1 {
+        if let Key::None(c) = key_sequence.keys[0] {
+            let is_seeds_focused = builder.focus == RecommendationsBuilderField::Seeds;
+            match (c, builder.focused_text_input_mut()) {
+                (crossterm::event::KeyCode::Char(c), Some(input)) => {
+                    input.push(c);
+                    return Ok(true);
+                }
+                (crossterm::event::KeyCode::Backspace, Some(input)) => {
+                    input.pop();
+                    return Ok(true);
+                }
+                // backspace on the seeds field removes the last added seed
+                (crossterm::event::KeyCode::Backspace, None) if is_seeds_focused => {
+                    builder.seeds.pop();
+                    return Ok(true);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let command = match state
+        .keymap_config
+        .find_command_from_key_sequence(key_sequence)
+    {
+        Some(command) => command,
+        None => return Ok(false),
+    };
+
+    match command {
+        Command::FocusNextWindow | Command::SelectNextOrScrollDown => builder.focus.next(),
+        Command::FocusPreviousWindow | Command::SelectPreviousOrScrollUp => {
+            builder.focus.previous()
+        }
+        Command::ChooseSelected => match builder.query() {
+            Ok(query) => browse_recommendations(query, client_pub, &mut ui)?,
+            Err(field) => builder.focus = field,
+        },
+        Command::ClosePopup => {
+            ui.popup = None;
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+/// handles a key sequence for a popup to input a Spotify URL or URI to open
+fn handle_key_sequence_for_open_link_popup(
+    key_sequence: &KeySequence,
+    client_pub: &flume::Sender<ClientRequest>,
+    state: &SharedState,
+) -> Result<bool> {
+    let mut ui = state.ui.lock();
+    let link = match ui.popup {
+        Some(PopupState::OpenLink { ref mut link }) => link,
+        _ => return Ok(false),
+    };
+
+    // handle user's input that updates the link
+    if key_sequence.keys.len() == 1 {
+        if let Key::None(c) = key_sequence.keys[0] {
+            match c {
+                crossterm::event::KeyCode::Char(c) => {
+                    link.push(c);
+                    return Ok(true);
+                }
+                crossterm::event::KeyCode::Backspace => {
+                    link.pop();
+                    return Ok(true);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let command = match state
+        .keymap_config
+        .find_command_from_key_sequence(key_sequence)
+    {
+        Some(command) => command,
+        None => return Ok(false),
+    };
+
+    match command {
+        Command::ChooseSelected => {
+            let link = crate::utils::parse_spotify_link(link)?;
+            ui.popup = None;
+            open_spotify_link(link, client_pub, &mut ui)?;
+        }
+        Command::ClosePopup => {
+            ui.popup = None;
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+/// handles a key sequence for a popup to input the new position of a playlist's track
+fn handle_key_sequence_for_playlist_track_position_popup(
+    key_sequence: &KeySequence,
+    client_pub: &flume::Sender<ClientRequest>,
+    state: &SharedState,
+) -> Result<bool> {
+    let mut ui = state.ui.lock();
+    let (playlist_id, track_index, position) = match ui.popup {
+        Some(PopupState::PlaylistTrackPosition {
+            ref playlist_id,
+            track_index,
+            ref mut position,
+        }) => (playlist_id.clone(), track_index, position),
+        _ => return Ok(false),
+    };
+
+    // handle user's input that updates the track's position
+    if key_sequence.keys.len() == 1 {
+        if let Key::None(c) = key_sequence.keys[0] {
+            match c {
+                crossterm::event::KeyCode::Char(c) if c.is_ascii_digit() => {
+                    position.push(c);
+                    return Ok(true);
+                }
+                crossterm::event::KeyCode::Backspace => {
+                    position.pop();
+                    return Ok(true);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let command = match state
+        .keymap_config
+        .find_command_from_key_sequence(key_sequence)
+    {
+        Some(command) => command,
+        None => return Ok(false),
+    };
+
+    match command {
+        Command::ChooseSelected => {
+            // the inputted position starts from 1
+            let to = match position.parse::<usize>() {
+                Ok(position) if position > 0 => position - 1,
+                _ => return Ok(true),
+            };
+            ui.popup = None;
+            if page::move_playlist_track(state, client_pub, &mut ui, &playlist_id, track_index, to)?
+            {
+                ui.current_page_mut().select(to);
+            }
+        }
+        Command::ClosePopup => {
+            ui.popup = None;
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+/// handles an action that can be applied to multiple tracks at once,
+/// the tracks are either a track with an action popup or the marked tracks
+fn handle_action_on_tracks(
+    action: TrackAction,
+    tracks: Vec<Track>,
+    client_pub: &flume::Sender<ClientRequest>,
+    state: &SharedState,
+    ui: &mut UIStateGuard,
+) -> Result<()> {
+    let track_ids = tracks.iter().map(|t| t.id.clone()).collect::<Vec<_>>();
+    match action {
+        TrackAction::AddToQueue => {
+            client_pub.send(ClientRequest::AddTracksToQueue(track_ids))?;
+        }
+        TrackAction::AddToPlaylist => {
+            // marked tracks are unmarked after choosing a playlist
+            client_pub.send(ClientRequest::GetUserPlaylists)?;
+            ui.popup = Some(PopupState::UserPlaylistList(
+                PlaylistPopupAction::AddTracks(track_ids),
+                new_list_state(),
+            ));
+            return Ok(());
+        }
+        TrackAction::AddToLikedTracks => {
+            client_pub.send(ClientRequest::AddTracksToLibrary(tracks))?;
+        }
+        TrackAction::DeleteFromLikedTracks => {
+            client_pub.send(ClientRequest::DeleteTracksFromLibrary(track_ids))?;
+        }
+        TrackAction::AddToRecommendationSeeds => {
+            let seeds = tracks.into_iter().map(SeedItem::Track).collect();
+            add_recommendation_seeds(seeds, ui);
+            ui.marked_tracks.clear();
+            return Ok(());
+        }
+        TrackAction::DeleteFromCurrentPlaylist => {
+            if let PageState::Context {
+                id: Some(ContextId::Playlist(playlist_id)),
+                ..
+            } = ui.current_page()
+            {
+                client_pub.send(ClientRequest::DeleteTracksFromPlaylist(
+                    playlist_id.clone(),
+                    track_ids,
+                ))?;
+            }
+        }
+        TrackAction::CopyLink => {
+            // each track's link is copied in a separate line
+            let links = tracks
+                .iter()
+                .map(|t| {
+                    crate::utils::format_share_link(
+                        &state.app_config.share_link_format,
+                        &t.id.uri(),
+                        &t.name,
+                        &t.artists_info(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            crate::utils::copy_to_clipboard(&links.join("\n"))?;
+        }
+        TrackAction::BrowseArtist
+        | TrackAction::BrowseAlbum
+        | TrackAction::BrowseRecommendations => {
+            anyhow::bail!("{action:?} cannot be applied to multiple tracks")
+        }
+    }
+    ui.popup = None;
+    ui.marked_tracks.clear();
+    Ok(())
+}
+
+/// copies a Spotify item's link, formatted by the `share_link_format` config option,
+/// to the clipboard
+fn copy_link(state: &SharedState, uri: &str, name: &str, artists: &str) -> Result<()> {
+    let link =
+        crate::utils::format_share_link(&state.app_config.share_link_format, uri, name, artists);
+    crate::utils::copy_to_clipboard(&link)?;
+    Ok(())
+}
+
+/// adds seeds to the recommendations builder, then opens the builder popup
+fn add_recommendation_seeds(seeds: Vec<SeedItem>, ui: &mut UIStateGuard) {
+    for seed in seeds {
+        if !ui.recommendations_builder.add_seed(seed) {
+            tracing::warn!(
+                "A recommendation query can have at most {MAX_RECOMMENDATION_SEEDS} seeds"
+            );
+            break;
+        }
+    }
+    ui.recommendations_builder.focus = RecommendationsBuilderField::Seeds;
+    ui.popup = Some(PopupState::RecommendationsBuilder);
+}
+
+/// requests recommendation tracks of a query and opens a new page to browse the tracks
+fn browse_recommendations(
+    query: RecommendationQuery,
+    client_pub: &flume::Sender<ClientRequest>,
+    ui: &mut UIStateGuard,
+) -> Result<()> {
+    let new_page = PageState::Tracks {
+        id: query.id(),
+        title: "Recommendations".to_string(),
+        desc: query.desc(),
+        query: Some(query.clone()),
+        state: new_table_state(),
+    };
+    client_pub.send(ClientRequest::GetRecommendations(query))?;
+    ui.create_new_page(new_page);
+    Ok(())
+}
+
 /// Handles a command for a context list popup in which each item represents a context
 ///
 /// In addition to application's states and the key sequence,
@@ -385,6 +780,7 @@ fn handle_command_for_action_list_popup(
     n_actions: usize,
     command: Command,
     client_pub: &flume::Sender<ClientRequest>,
+    state: &SharedState,
     ui: UIStateGuard,
 ) -> Result<bool> {
     handle_command_for_list_popup(
@@ -419,53 +815,19 @@ fn handle_command_for_action_list_popup(
                             new_list_state(),
                         ));
                     }
-                    TrackAction::AddToQueue => {
-                        client_pub.send(ClientRequest::AddTrackToQueue(track.id.clone()))?;
-                        ui.popup = None;
-                    }
-                    TrackAction::AddToPlaylist => {
-                        client_pub.send(ClientRequest::GetUserPlaylists)?;
-                        ui.popup = Some(PopupState::UserPlaylistList(
-                            PlaylistPopupAction::AddTrack(track.id.clone()),
-                            new_list_state(),
-                        ));
-                    }
-                    TrackAction::AddToLikedTracks => {
-                        client_pub.send(ClientRequest::AddToLibrary(Item::Track(track.clone())))?;
-                        ui.popup = None;
-                    }
                     TrackAction::BrowseRecommendations => {
-                        client_pub.send(ClientRequest::GetRecommendations(SeedItem::Track(
-                            track.clone(),
-                        )))?;
-                        let new_page = PageState::Tracks {
-                            id: format!("recommendations::{}", track.id.uri()),
-                            title: "Recommendations".to_string(),
-                            desc: format!("{} Radio", track.name),
-                            state: new_table_state(),
-                        };
-                        ui.create_new_page(new_page);
+                        let query = RecommendationQuery::from_seed(SeedItem::Track(track.clone()));
+                        browse_recommendations(query, client_pub, ui)?;
                     }
-                    TrackAction::DeleteFromLikedTracks => {
-                        client_pub.send(ClientRequest::DeleteFromLibrary(ItemId::Track(
-                            track.id.clone(),
-                        )))?;
-                        ui.popup = None;
-                    }
-                    TrackAction::DeleteFromCurrentPlaylist => {
-                        if let PageState::Context {
-                            id: Some(ContextId::Playlist(playlist_id)),
-                            ..
-                        } = ui.current_page()
-                        {
-                            client_pub.send(ClientRequest::DeleteTrackFromPlaylist(
-                                playlist_id.clone(),
-                                track.id.clone(),
-                            ))?;
-                        }
-                        ui.popup = None;
+                    action => {
+                        let tracks = vec![track.clone()];
+                        handle_action_on_tracks(action, tracks, client_pub, state, ui)?;
                     }
                 },
+                ActionListItem::Tracks(tracks, actions) => {
+                    let (action, tracks) = (actions[id], tracks.clone());
+                    handle_action_on_tracks(action, tracks, client_pub, state, ui)?;
+                }
                 ActionListItem::Album(album, actions) => match actions[id] {
                     AlbumAction::BrowseArtist => {
                         ui.popup = Some(PopupState::ArtistList(
@@ -483,6 +845,11 @@ fn handle_command_for_action_list_popup(
                         )))?;
                         ui.popup = None;
                     }
+                    AlbumAction::CopyLink => {
+                        let artists = crate::utils::map_join(&album.artists, |a| &a.name, ", ");
+                        copy_link(state, &album.id.uri(), &album.name, &artists)?;
+                        ui.popup = None;
+                    }
                 },
                 ActionListItem::Artist(artist, actions) => match actions[id] {
                     ArtistAction::Follow => {
@@ -491,16 +858,13 @@ fn handle_command_for_action_list_popup(
                         ui.popup = None;
                     }
                     ArtistAction::BrowseRecommendations => {
-                        client_pub.send(ClientRequest::GetRecommendations(SeedItem::Artist(
-                            artist.clone(),
-                        )))?;
-                        let new_page = PageState::Tracks {
-                            id: format!("recommendations::{}", artist.id.uri()),
-                            title: "Recommendations".to_string(),
-                            desc: format!("{} Radio", artist.name),
-                            state: new_table_state(),
-                        };
-                        ui.create_new_page(new_page);
+                        let query =
+                            RecommendationQuery::from_seed(SeedItem::Artist(artist.clone()));
+                        browse_recommendations(query, client_pub, ui)?;
+                    }
+                    ArtistAction::AddToRecommendationSeeds => {
+                        let seed = SeedItem::Artist(artist.clone());
+                        add_recommendation_seeds(vec![seed], ui);
                     }
                     ArtistAction::Unfollow => {
                         client_pub.send(ClientRequest::DeleteFromLibrary(ItemId::Artist(
@@ -508,6 +872,10 @@ fn handle_command_for_action_list_popup(
                         )))?;
                         ui.popup = None;
                     }
+                    ArtistAction::CopyLink => {
+                        copy_link(state, &artist.id.uri(), &artist.name, &artist.name)?;
+                        ui.popup = None;
+                    }
                 },
                 ActionListItem::Playlist(playlist, actions) => match actions[id] {
                     PlaylistAction::AddToLibrary => {
@@ -516,12 +884,32 @@ fn handle_command_for_action_list_popup(
                         )))?;
                         ui.popup = None;
                     }
-                    PlaylistAction::DeleteFromLibrary => {
+                    // deleting a playlist is the same as unfollowing the playlist
+                    // as Spotify doesn't provide an API to delete a playlist
+                    PlaylistAction::DeleteFromLibrary | PlaylistAction::Delete => {
                         client_pub.send(ClientRequest::DeleteFromLibrary(ItemId::Playlist(
                             playlist.id.clone(),
                         )))?;
                         ui.popup = None;
                     }
+                    PlaylistAction::Edit => {
+                        // the playlist's description is only available in the playlist's context
+                        let playlist =
+                            match state.data.read().caches.context.peek(&playlist.id.uri()) {
+                                Some(crate::state::Context::Playlist { playlist, .. }) => {
+                                    playlist.clone()
+                                }
+                                _ => playlist.clone(),
+                            };
+                        ui.popup = Some(PopupState::PlaylistEditor(
+                            PlaylistEditorState::from_playlist(playlist),
+                        ));
+                    }
+                    PlaylistAction::CopyLink => {
+                        let owner = &playlist.owner.0;
+                        copy_link(state, &playlist.id.uri(), &playlist.name, owner)?;
+                        ui.popup = None;
+                    }
                 },
             }
             Ok(())
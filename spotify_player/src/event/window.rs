@@ -28,6 +28,9 @@ pub fn handle_command_for_focused_context_window(
             Context::Artist {
                 top_tracks,
                 albums,
+                singles,
+                compilations,
+                appears_on,
                 related_artists,
                 ..
             } => {
@@ -40,12 +43,23 @@ pub fn handle_command_for_focused_context_window(
                 };
 
                 match focus_state {
-                    ArtistFocusState::Albums => handle_command_for_album_list_window(
-                        command,
-                        ui.search_filtered_items(albums),
-                        &data,
-                        ui,
-                    ),
+                    ArtistFocusState::Albums
+                    | ArtistFocusState::Singles
+                    | ArtistFocusState::Compilations
+                    | ArtistFocusState::AppearsOn => {
+                        let albums = match focus_state {
+                            ArtistFocusState::Albums => albums,
+                            ArtistFocusState::Singles => singles,
+                            ArtistFocusState::Compilations => compilations,
+                            _ => appears_on,
+                        };
+                        handle_command_for_album_list_window(
+                            command,
+                            ui.search_filtered_items(albums),
+                            &data,
+                            ui,
+                        )
+                    }
                     ArtistFocusState::RelatedArtists => handle_command_for_artist_list_window(
                         command,
                         ui.search_filtered_items(related_artists),
@@ -81,6 +95,13 @@ pub fn handle_command_for_focused_context_window(
                 &data,
                 ui,
             ),
+            Context::Show { show, episodes } => handle_command_for_episode_table_window(
+                command,
+                client_pub,
+                ContextId::Show(show.id.clone()),
+                ui.search_filtered_items(episodes),
+                ui,
+            ),
         },
         None => Ok(false),
     }
@@ -139,15 +160,64 @@ pub fn handle_command_for_track_table_window(
                 )))?;
             }
         }
+        Command::ToggleMarkTrack => {
+            ui.toggle_marked_track(tracks[id]);
+            if id + 1 < tracks.len() {
+                ui.current_page_mut().select(id + 1);
+            }
+        }
         Command::ShowActionsOnSelectedItem => {
-            let mut actions = command::construct_track_actions(tracks[id], data);
-            if let Some(ContextId::Playlist(_)) = context_id {
-                actions.push(TrackAction::DeleteFromCurrentPlaylist);
+            let is_playlist = matches!(context_id, Some(ContextId::Playlist(_)));
+            let item = if ui.marked_tracks.is_empty() {
+                let mut actions = command::construct_track_actions(tracks[id], data);
+                if is_playlist {
+                    actions.push(TrackAction::DeleteFromCurrentPlaylist);
+                }
+                ActionListItem::Track(tracks[id].clone(), actions)
+            } else {
+                let mut actions = command::construct_batch_track_actions(&ui.marked_tracks, data);
+                if is_playlist {
+                    actions.push(TrackAction::DeleteFromCurrentPlaylist);
+                }
+                ActionListItem::Tracks(ui.marked_tracks.clone(), actions)
+            };
+            ui.popup = Some(PopupState::ActionList(item, new_list_state()));
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+/// handles a command for the episode table subwindow of a show context window.
+/// Playing an episode in the table will start a `Context` playback representing the show.
+pub fn handle_command_for_episode_table_window(
+    command: Command,
+    client_pub: &flume::Sender<ClientRequest>,
+    context_id: ContextId,
+    episodes: Vec<&Episode>,
+    mut ui: UIStateGuard,
+) -> Result<bool> {
+    let id = ui.current_page_mut().selected().unwrap_or_default();
+    if id >= episodes.len() {
+        return Ok(false);
+    }
+
+    match command {
+        Command::SelectNextOrScrollDown => {
+            if id + 1 < episodes.len() {
+                ui.current_page_mut().select(id + 1);
+            }
+        }
+        Command::SelectPreviousOrScrollUp => {
+            if id > 0 {
+                ui.current_page_mut().select(id - 1);
             }
-            ui.popup = Some(PopupState::ActionList(
-                ActionListItem::Track(tracks[id].clone(), actions),
-                new_list_state(),
-            ));
+        }
+        Command::ChooseSelected => {
+            let offset = Some(rspotify_model::Offset::for_uri(&episodes[id].id.uri()));
+            client_pub.send(ClientRequest::Player(PlayerRequest::StartPlayback(
+                Playback::Context(context_id, offset),
+            )))?;
         }
         _ => return Ok(false),
     }
@@ -187,12 +257,21 @@ pub fn handle_command_for_track_list_window(
                 Playback::URIs(vec![tracks[id].id.clone()], None),
             )))?;
         }
+        Command::ToggleMarkTrack => {
+            ui.toggle_marked_track(tracks[id]);
+            if id + 1 < tracks.len() {
+                ui.current_page_mut().select(id + 1);
+            }
+        }
         Command::ShowActionsOnSelectedItem => {
-            let actions = command::construct_track_actions(tracks[id], data);
-            ui.popup = Some(PopupState::ActionList(
-                ActionListItem::Track(tracks[id].clone(), actions),
-                new_list_state(),
-            ));
+            let item = if ui.marked_tracks.is_empty() {
+                let actions = command::construct_track_actions(tracks[id], data);
+                ActionListItem::Track(tracks[id].clone(), actions)
+            } else {
+                let actions = command::construct_batch_track_actions(&ui.marked_tracks, data);
+                ActionListItem::Tracks(ui.marked_tracks.clone(), actions)
+            };
+            ui.popup = Some(PopupState::ActionList(item, new_list_state()));
         }
         _ => return Ok(false),
     }
@@ -230,7 +309,10 @@ pub fn handle_command_for_artist_list_window(
             });
         }
         Command::ShowActionsOnSelectedItem => {
-            let mut actions = vec![ArtistAction::BrowseRecommendations];
+            let mut actions = vec![
+                ArtistAction::BrowseRecommendations,
+                ArtistAction::AddToRecommendationSeeds,
+            ];
             if data
                 .user_data
                 .followed_artists
@@ -241,6 +323,7 @@ pub fn handle_command_for_artist_list_window(
             } else {
                 actions.push(ArtistAction::Follow);
             }
+            actions.push(ArtistAction::CopyLink);
             ui.popup = Some(PopupState::ActionList(
                 ActionListItem::Artist(artists[id].clone(), actions),
                 new_list_state(),
@@ -293,6 +376,7 @@ pub fn handle_command_for_album_list_window(
             } else {
                 actions.push(AlbumAction::AddToLibrary);
             }
+            actions.push(AlbumAction::CopyLink);
             ui.popup = Some(PopupState::ActionList(
                 ActionListItem::Album(albums[id].clone(), actions),
                 new_list_state(),
@@ -303,6 +387,40 @@ pub fn handle_command_for_album_list_window(
     Ok(true)
 }
 
+pub fn handle_command_for_show_list_window(
+    command: Command,
+    shows: Vec<&Show>,
+    mut ui: UIStateGuard,
+) -> Result<bool> {
+    let id = ui.current_page_mut().selected().unwrap_or_default();
+    if id >= shows.len() {
+        return Ok(false);
+    }
+
+    match command {
+        Command::SelectNextOrScrollDown => {
+            if id + 1 < shows.len() {
+                ui.current_page_mut().select(id + 1);
+            }
+        }
+        Command::SelectPreviousOrScrollUp => {
+            if id > 0 {
+                ui.current_page_mut().select(id - 1);
+            }
+        }
+        Command::ChooseSelected => {
+            let context_id = ContextId::Show(shows[id].id.clone());
+            ui.create_new_page(PageState::Context {
+                id: None,
+                context_page_type: ContextPageType::Browsing(context_id),
+                state: None,
+            });
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
 pub fn handle_command_for_playlist_list_window(
     command: Command,
     playlists: Vec<&Playlist>,
@@ -335,7 +453,16 @@ pub fn handle_command_for_playlist_list_window(
         }
         Command::ShowActionsOnSelectedItem => {
             let mut actions = vec![];
-            if data
+            let is_owned = data
+                .user_data
+                .user
+                .as_ref()
+                .map(|u| u.id == playlists[id].owner.1)
+                .unwrap_or_default();
+            if is_owned {
+                actions.push(PlaylistAction::Edit);
+                actions.push(PlaylistAction::Delete);
+            } else if data
                 .user_data
                 .playlists
                 .iter()
@@ -345,6 +472,7 @@ pub fn handle_command_for_playlist_list_window(
             } else {
                 actions.push(PlaylistAction::AddToLibrary);
             }
+            actions.push(PlaylistAction::CopyLink);
             ui.popup = Some(PopupState::ActionList(
                 ActionListItem::Playlist(playlists[id].clone(), actions),
                 new_list_state(),
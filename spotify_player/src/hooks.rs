@@ -0,0 +1,183 @@
+use crate::{config::HooksConfig, state::*};
+
+/// the maximum duration a hook command can run before it's killed
+const HOOK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A player event that can trigger a user-defined hook
+pub enum PlayerEvent {
+    TrackChange,
+    PlaybackPause,
+    PlaybackResume,
+    DeviceChange,
+    ContextChange,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// A snapshot of the player's data, which is used to detect player events
+/// by comparing two consecutive snapshots
+pub struct PlayerSnapshot {
+    pub track_id: Option<String>,
+    pub track_name: Option<String>,
+    pub artists: Option<String>,
+    pub album: Option<String>,
+    pub is_playing: bool,
+    pub device_id: Option<String>,
+    pub device_name: Option<String>,
+    pub context_uri: Option<String>,
+}
+
+impl PlayerEvent {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::TrackChange => "track_change",
+            Self::PlaybackPause => "playback_pause",
+            Self::PlaybackResume => "playback_resume",
+            Self::DeviceChange => "device_change",
+            Self::ContextChange => "context_change",
+        }
+    }
+
+    fn hook<'a>(&self, hooks: &'a HooksConfig) -> Option<&'a String> {
+        match self {
+            Self::TrackChange => hooks.track_change.as_ref(),
+            Self::PlaybackPause => hooks.playback_pause.as_ref(),
+            Self::PlaybackResume => hooks.playback_resume.as_ref(),
+            Self::DeviceChange => hooks.device_change.as_ref(),
+            Self::ContextChange => hooks.context_change.as_ref(),
+        }
+    }
+}
+
+impl PlayerSnapshot {
+    /// creates a snapshot of the current player's data
+    pub fn new(player: &PlayerState) -> Option<Self> {
+        let playback = player.playback.as_ref()?;
+        // a playing episode's show publisher and show name are used as its artists and album
+        let (track_id, track_name, artists, album) = match playback.item {
+            Some(rspotify::model::PlayableItem::Track(ref track)) => (
+                track.id.as_ref().map(|id| id.id().to_string()),
+                Some(track.name.clone()),
+                Some(crate::utils::map_join(&track.artists, |a| &a.name, ", ")),
+                Some(track.album.name.clone()),
+            ),
+            Some(rspotify::model::PlayableItem::Episode(ref episode)) => (
+                Some(episode.id.id().to_string()),
+                Some(episode.name.clone()),
+                Some(episode.show.publisher.clone()),
+                Some(episode.show.name.clone()),
+            ),
+            None => (None, None, None, None),
+        };
+
+        Some(Self {
+            track_id,
+            track_name,
+            artists,
+            album,
+            is_playing: playback.is_playing,
+            device_id: playback.device.id.clone(),
+            device_name: Some(playback.device.name.clone()),
+            context_uri: playback.context.as_ref().map(|c| c.uri.clone()),
+        })
+    }
+
+    /// returns the player events happened between the previous snapshot and the current snapshot
+    pub fn events_since(&self, prev: &Self) -> Vec<PlayerEvent> {
+        let mut events = vec![];
+        if self.track_id != prev.track_id {
+            events.push(PlayerEvent::TrackChange);
+        }
+        if self.is_playing != prev.is_playing {
+            events.push(if self.is_playing {
+                PlayerEvent::PlaybackResume
+            } else {
+                PlayerEvent::PlaybackPause
+            });
+        }
+        if self.device_id != prev.device_id {
+            events.push(PlayerEvent::DeviceChange);
+        }
+        if self.context_uri != prev.context_uri {
+            events.push(PlayerEvent::ContextChange);
+        }
+        events
+    }
+
+    /// returns the snapshot's data as a list of environment variables passed to hook commands
+    fn envs(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("SPOTIFY_PLAYER_TRACK_ID", self.track_id.clone()),
+            ("SPOTIFY_PLAYER_TRACK_NAME", self.track_name.clone()),
+            ("SPOTIFY_PLAYER_ARTISTS", self.artists.clone()),
+            ("SPOTIFY_PLAYER_ALBUM", self.album.clone()),
+            (
+                "SPOTIFY_PLAYER_IS_PLAYING",
+                Some(self.is_playing.to_string()),
+            ),
+            ("SPOTIFY_PLAYER_DEVICE_ID", self.device_id.clone()),
+            ("SPOTIFY_PLAYER_DEVICE_NAME", self.device_name.clone()),
+            ("SPOTIFY_PLAYER_CONTEXT_URI", self.context_uri.clone()),
+        ]
+        .into_iter()
+        .map(|(k, v)| (k, v.unwrap_or_default()))
+        .collect()
+    }
+}
+
+/// runs the user-defined hook command (if any) of a player event
+pub fn run_hook(event: PlayerEvent, snapshot: &PlayerSnapshot, hooks: &HooksConfig) {
+    let hook = match event.hook(hooks) {
+        Some(hook) => hook,
+        None => return,
+    };
+    tracing::info!("Running the hook command {hook:?} of the {event:?} event");
+
+    #[cfg(not(windows))]
+    let mut cmd = {
+        let mut cmd = tokio::process::Command::new("sh");
+        cmd.arg("-c").arg(hook);
+        cmd
+    };
+    #[cfg(windows)]
+    let mut cmd = {
+        let mut cmd = tokio::process::Command::new("cmd");
+        cmd.arg("/C").arg(hook);
+        cmd
+    };
+    cmd.env("SPOTIFY_PLAYER_EVENT", event.name())
+        .envs(snapshot.envs())
+        .stdin(std::process::Stdio::null())
+        .kill_on_drop(true);
+
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(err) => {
+            tracing::error!("Failed to run the hook command of the {event:?} event: {err:#}");
+            return;
+        }
+    };
+
+    // wait for the hook command in a separate task to not block the caller
+    tokio::task::spawn(async move {
+        match tokio::time::timeout(HOOK_TIMEOUT, child.wait()).await {
+            Err(_) => {
+                tracing::warn!(
+                    "The hook command of the {event:?} event timed out after {HOOK_TIMEOUT:?}, killing it..."
+                );
+                if let Err(err) = child.kill().await {
+                    tracing::error!(
+                        "Failed to kill the hook command of the {event:?} event: {err:#}"
+                    );
+                }
+            }
+            Ok(Err(err)) => {
+                tracing::error!("Failed to run the hook command of the {event:?} event: {err:#}")
+            }
+            Ok(Ok(status)) if !status.success() => {
+                tracing::warn!("The hook command of the {event:?} event exited with {status}")
+            }
+            Ok(Ok(_)) => {}
+        }
+    });
+}
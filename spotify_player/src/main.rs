@@ -1,11 +1,14 @@
 mod auth;
+mod cli;
 mod client;
 mod command;
 mod config;
 mod event;
+mod hooks;
 mod key;
 #[cfg(feature = "media-control")]
 mod media_control;
+mod socket;
 mod state;
 #[cfg(feature = "streaming")]
 mod streaming;
@@ -44,6 +47,19 @@ fn init_app_cli_arguments() -> clap::ArgMatches {
                 .help("Path to the application's cache folder (default: $HOME/.cache/spotify-player)")
                 .next_line_help(true)
         )
+        .arg(
+            clap::Arg::new("list-audio-devices")
+                .long("list-audio-devices")
+                .help("List the available audio backends and output devices of the integrated player")
+                .next_line_help(true)
+        )
+        .arg(
+            clap::Arg::new("daemon")
+                .short('d')
+                .long("daemon")
+                .help("Run the application as a daemon without the terminal UI")
+        )
+        .subcommands(cli::init_cli_subcommands())
         .get_matches()
 }
 
@@ -58,7 +74,7 @@ async fn init_spotify(
     // if `streaming` feature is enabled, create a new streaming connection
     #[cfg(feature = "streaming")]
     client
-        .new_streaming_connection(streaming_sub.clone(), client_pub.clone())
+        .new_streaming_connection(state, streaming_sub.clone(), client_pub.clone())
         .await
         .context("failed to create a new streaming connection")?;
 
@@ -76,10 +92,31 @@ async fn init_spotify(
     client_pub.send(event::ClientRequest::GetUserFollowedArtists)?;
     client_pub.send(event::ClientRequest::GetUserSavedAlbums)?;
     client_pub.send(event::ClientRequest::GetUserSavedTracks)?;
+    client_pub.send(event::ClientRequest::GetUserSavedShows)?;
 
     Ok(())
 }
 
+/// sets the `RUST_LOG` environment variable to the default logging filter if it's not specified
+fn set_default_log_filter(filter: &str) {
+    if std::env::var("RUST_LOG").is_err() {
+        std::env::set_var("RUST_LOG", filter);
+    }
+}
+
+/// initializes the logging of a CLI subcommand, logs are written to stderr
+/// to not be mixed with the subcommand's output
+fn init_cli_logging() {
+    // default to only log warnings and the CLI's own messages,
+    // e.g. not the configurations logged when initializing the application's state
+    set_default_log_filter("spotify_player=warn,spotify_player::cli=info");
+    tracing_subscriber::fmt::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .with_writer(std::io::stderr)
+        .without_time()
+        .init();
+}
+
 fn init_logging(cache_folder: &std::path::Path) -> Result<()> {
     let log_prefix = format!(
         "spotify-player-{}",
@@ -87,9 +124,7 @@ fn init_logging(cache_folder: &std::path::Path) -> Result<()> {
     );
 
     // initialize the application's logging
-    if std::env::var("RUST_LOG").is_err() {
-        std::env::set_var("RUST_LOG", "spotify_player=info"); // default to log the current crate only
-    }
+    set_default_log_filter("spotify_player=info"); // default to log the current crate only
     let log_file = std::fs::File::create(cache_folder.join(format!("{log_prefix}.log")))
         .context("failed to create log file")?;
     tracing_subscriber::fmt::fmt()
@@ -135,16 +170,38 @@ async fn main() -> Result<()> {
         std::fs::create_dir_all(&cache_audio_folder)?;
     }
 
+    // handle a CLI subcommand (if any) without starting the application
+    if let Some((cmd, args)) = args.subcommand() {
+        init_cli_logging();
+        return cli::handle_cli_subcommand(cmd, args, &config_folder, &cache_folder).await;
+    }
+
     init_logging(&cache_folder).context("failed to initialize application's logging")?;
 
+    let is_daemon = args.is_present("daemon");
+    if is_daemon {
+        tracing::info!("Running the application in daemon mode...");
+    }
+
     // initialize the application state
     let state = {
         let mut state = state::State::default();
         // parse config options from the config files into application's state
         state.parse_config_files(&config_folder, args.value_of("theme"))?;
+        // load the application's data persisted from the previous runs
+        if let Err(err) = state.data.get_mut().load_persisted_data(&cache_folder) {
+            tracing::warn!("Failed to load the application's persisted data: {err:#}");
+        }
         std::sync::Arc::new(state)
     };
 
+    if args.is_present("list-audio-devices") {
+        #[cfg(feature = "streaming")]
+        return streaming::list_audio_devices(&state.app_config.device);
+        #[cfg(not(feature = "streaming"))]
+        anyhow::bail!("listing audio devices requires the `streaming` feature");
+    }
+
     // create a librespot session
     let session = auth::new_session(
         &cache_folder,
@@ -153,11 +210,16 @@ async fn main() -> Result<()> {
     )
     .await?;
 
+    // data persisting channels, which are used to notify the data persister to persist the application's data
+    let (persist_pub, persist_sub) = flume::unbounded::<()>();
+
     // create a spotify API client
     let client = client::Client::new(
         session.clone(),
         state.app_config.device.clone(),
         state.app_config.client_id.clone(),
+        cache_folder.clone(),
+        persist_pub,
     );
 
     // client channels
@@ -180,6 +242,27 @@ async fn main() -> Result<()> {
 
     // Spawn application's tasks
 
+    // data persister task
+    tokio::task::spawn({
+        let state = state.clone();
+        let cache_folder = cache_folder.clone();
+        async move {
+            client::start_data_persister(state, cache_folder, persist_sub).await;
+        }
+    });
+
+    // session supervisor task, which reconnects the Librespot session when it's terminated
+    #[cfg(feature = "streaming")]
+    tokio::task::spawn({
+        let state = state.clone();
+        let client = client.clone();
+        let client_pub = client_pub.clone();
+        let streaming_sub = streaming_sub.clone();
+        async move {
+            client::start_session_supervisor(state, client, client_pub, streaming_sub).await;
+        }
+    });
+
     // client event handler task
     tokio::task::spawn({
         let state = state.clone();
@@ -197,14 +280,16 @@ async fn main() -> Result<()> {
         }
     });
 
-    // terminal event handler task
-    tokio::task::spawn_blocking({
-        let client_pub = client_pub.clone();
-        let state = state.clone();
-        move || {
-            event::start_event_handler(state, client_pub);
-        }
-    });
+    // terminal event handler task, which is not needed when running as a daemon
+    if !is_daemon {
+        tokio::task::spawn_blocking({
+            let client_pub = client_pub.clone();
+            let state = state.clone();
+            move || {
+                event::start_event_handler(state, client_pub);
+            }
+        });
+    }
 
     // player event watcher task
     tokio::task::spawn({
@@ -215,13 +300,31 @@ async fn main() -> Result<()> {
         }
     });
 
-    // application UI task
-    #[allow(unused_variables)]
-    let ui_task = tokio::task::spawn_blocking({
+    // IPC socket server task, which allows controlling the application from other processes
+    #[cfg(unix)]
+    tokio::task::spawn({
         let state = state.clone();
-        move || ui::run(state)
+        let client_pub = client_pub.clone();
+        let socket_path = cache_folder.join(socket::SOCKET_FILE);
+        async move {
+            if let Err(err) = socket::start_socket_server(socket_path, state, client_pub).await {
+                tracing::error!("Failed to start the application's IPC socket server: {err:#}");
+            }
+        }
     });
 
+    // application UI task, which is not needed when running as a daemon
+    #[allow(unused_variables)]
+    let ui_task = if is_daemon {
+        None
+    } else {
+        Some(tokio::task::spawn_blocking({
+            let state = state.clone();
+            let cache_folder = cache_folder.clone();
+            move || ui::run(state, &cache_folder)
+        }))
+    };
+
     #[cfg(feature = "media-control")]
     if state.app_config.enable_media_control {
         // media control task
@@ -251,7 +354,11 @@ async fn main() -> Result<()> {
     }
     #[allow(unreachable_code)]
     {
-        ui_task.await??;
+        match ui_task {
+            Some(ui_task) => ui_task.await??,
+            // a daemon keeps running the spawned tasks until being terminated
+            None => std::future::pending::<()>().await,
+        }
         Ok(())
     }
 }
@@ -0,0 +1,234 @@
+use crate::{
+    event::{ClientRequest, PlayerRequest},
+    state::*,
+};
+use anyhow::{Context as _, Result};
+use serde::{Deserialize, Serialize};
+#[cfg(unix)]
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
+    net::{UnixListener, UnixStream},
+};
+
+/// the application's IPC socket file, which is placed inside the application's cache folder
+pub const SOCKET_FILE: &str = "spotify-player.sock";
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+/// A request sent to the application's IPC socket.
+///
+/// Each request is a JSON object written in a single line, e.g.
+/// `{"command": "volume", "percent": 40}`.
+pub enum Request {
+    Play,
+    Pause,
+    ResumePause,
+    Next,
+    Previous,
+    Repeat,
+    Shuffle,
+    Seek {
+        position_ms: u32,
+    },
+    Volume {
+        percent: u8,
+    },
+    Queue {
+        uri: String,
+    },
+    Search {
+        query: String,
+    },
+    Open {
+        uri: String,
+    },
+    GetPlayback {
+        #[serde(default)]
+        cached: bool,
+    },
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+/// A response returned by the application's IPC socket, which is
+/// also a JSON object written in a single line.
+pub struct Response {
+    pub ok: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl From<Result<Option<serde_json::Value>>> for Response {
+    fn from(result: Result<Option<serde_json::Value>>) -> Self {
+        match result {
+            Ok(data) => Self {
+                ok: true,
+                data,
+                error: None,
+            },
+            Err(err) => Self {
+                ok: false,
+                data: None,
+                error: Some(format!("{err:#}")),
+            },
+        }
+    }
+}
+
+/// starts the application's IPC socket server listening on a given socket path
+#[cfg(unix)]
+pub async fn start_socket_server(
+    path: std::path::PathBuf,
+    state: SharedState,
+    client_pub: flume::Sender<ClientRequest>,
+) -> Result<()> {
+    if path.exists() {
+        // the socket file can be left over from a previous run of the application,
+        // it's only safe to remove the file if no running instance is listening on it
+        if UnixStream::connect(&path).await.is_ok() {
+            anyhow::bail!("another application instance is listening on {path:?}");
+        }
+        std::fs::remove_file(&path).context("failed to remove the stale socket file")?;
+    }
+
+    let listener = UnixListener::bind(&path)?;
+    tracing::info!("Starting the application's IPC socket server on {path:?}...");
+
+    loop {
+        let (stream, _) = listener.accept().await?;
+        tokio::task::spawn({
+            let state = state.clone();
+            let client_pub = client_pub.clone();
+            async move {
+                if let Err(err) = handle_connection(stream, &state, &client_pub).await {
+                    tracing::warn!("Failed to handle a socket connection: {err:#}");
+                }
+            }
+        });
+    }
+}
+
+/// handles requests sent via a socket connection until the connection is closed
+#[cfg(unix)]
+async fn handle_connection(
+    stream: UnixStream,
+    state: &SharedState,
+    client_pub: &flume::Sender<ClientRequest>,
+) -> Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = BufReader::new(reader).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response: Response = match serde_json::from_str::<Request>(&line) {
+            Ok(request) => {
+                tracing::info!("Got a socket request: {request:?}");
+                handle_socket_request(request, state, client_pub)
+                    .await
+                    .into()
+            }
+            Err(err) => Err(anyhow::anyhow!("invalid request {line:?}: {err}")).into(),
+        };
+
+        let mut response = serde_json::to_string(&response)?;
+        response.push('\n');
+        writer.write_all(response.as_bytes()).await?;
+    }
+
+    Ok(())
+}
+
+/// handles a socket request by forwarding it to the client as one or more `ClientRequest`,
+/// then returns the request's data (if any).
+pub async fn handle_socket_request(
+    request: Request,
+    state: &SharedState,
+    client_pub: &flume::Sender<ClientRequest>,
+) -> Result<Option<serde_json::Value>> {
+    match request {
+        Request::Play => send_player_request(PlayerRequest::Resume, client_pub).await?,
+        Request::Pause => send_player_request(PlayerRequest::Pause, client_pub).await?,
+        Request::ResumePause => send_player_request(PlayerRequest::ResumePause, client_pub).await?,
+        Request::Next => send_player_request(PlayerRequest::NextTrack, client_pub).await?,
+        Request::Previous => send_player_request(PlayerRequest::PreviousTrack, client_pub).await?,
+        Request::Repeat => send_player_request(PlayerRequest::Repeat, client_pub).await?,
+        Request::Shuffle => send_player_request(PlayerRequest::Shuffle, client_pub).await?,
+        Request::Seek { position_ms } => {
+            send_player_request(PlayerRequest::SeekTrack(position_ms), client_pub).await?
+        }
+        Request::Volume { percent } => {
+            send_player_request(PlayerRequest::Volume(percent.min(100)), client_pub).await?
+        }
+        Request::Queue { uri } => {
+            let track_id = TrackId::from_id_or_uri(&crate::utils::parse_uri(&uri))
+                .with_context(|| format!("invalid track URI {uri}"))?;
+            send_request(ClientRequest::AddTracksToQueue(vec![track_id]), client_pub).await?;
+        }
+        Request::Search { query } => {
+            send_request(ClientRequest::Search(query.clone()), client_pub).await?;
+            let data = state.data.read();
+            let results = data
+                .caches
+                .search
+                .peek(&query)
+                .context("search results should be cached after a search request")?;
+            return Ok(Some(serde_json::to_value(results)?));
+        }
+        Request::Open { uri } => {
+            match crate::utils::parse_spotify_link(&uri)? {
+                // a track's playback is started by the client, whose result is sent back to the caller
+                SpotifyLink::Track(id) => {
+                    send_player_request(
+                        PlayerRequest::StartPlayback(Playback::URIs(vec![id], None)),
+                        client_pub,
+                    )
+                    .await?
+                }
+                link => crate::event::open_spotify_link(link, client_pub, &mut state.ui.lock())?,
+            }
+        }
+        Request::GetPlayback { cached } => {
+            // a cached playback is kept up to date by the running application's player event watchers
+            if !cached {
+                send_request(ClientRequest::GetCurrentPlayback, client_pub).await?;
+            }
+            let player = state.player.read();
+            // the returned playback's progress should include the time
+            // passed since the last playback update
+            let playback = player.playback.clone().map(|mut playback| {
+                playback.progress = player.playback_progress();
+                playback
+            });
+            return Ok(Some(serde_json::to_value(playback)?));
+        }
+    }
+
+    Ok(None)
+}
+
+/// sends a player request to the client and waits for the request to be handled
+async fn send_player_request(
+    request: PlayerRequest,
+    client_pub: &flume::Sender<ClientRequest>,
+) -> Result<()> {
+    send_request(ClientRequest::Player(request), client_pub).await
+}
+
+/// sends a request to the client and waits for the request to be handled
+async fn send_request(
+    request: ClientRequest,
+    client_pub: &flume::Sender<ClientRequest>,
+) -> Result<()> {
+    let (response_pub, response_sub) = flume::bounded(1);
+    client_pub
+        .send_async(ClientRequest::WithResponse(Box::new(request), response_pub))
+        .await?;
+    response_sub
+        .recv_async()
+        .await
+        .context("failed to receive the request's response")?
+}
@@ -1,7 +1,17 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
 
 use super::model::*;
 
+/// the file storing the persisted user data inside the application's cache folder
+const USER_DATA_CACHE_FILE: &str = "user_data.json";
+/// the file storing the persisted context caches inside the application's cache folder
+const CONTEXT_CACHE_FILE: &str = "context_cache.json";
+/// the maximum number of queries stored in the search history
+const SEARCH_HISTORY_LIMIT: usize = 100;
+
 pub type DataReadGuard<'a> = parking_lot::RwLockReadGuard<'a, AppData>;
 
 #[derive(Default, Debug)]
@@ -12,7 +22,7 @@ pub struct AppData {
     pub browse: BrowseData,
 }
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Serialize, Deserialize)]
 /// current user's data
 pub struct UserData {
     pub user: Option<rspotify_model::PrivateUser>,
@@ -20,12 +30,33 @@ pub struct UserData {
     pub followed_artists: Vec<Artist>,
     pub saved_albums: Vec<Album>,
     pub saved_tracks: Vec<Track>,
+    #[serde(default)]
+    pub saved_shows: Vec<Show>,
+    /// the user's search queries, from the most recent to the least recent
+    #[serde(default)]
+    pub search_history: Vec<String>,
 }
 
 #[derive(Debug)]
 /// the application's caches
 pub struct Caches {
     pub context: lru::LruCache<String, Context>,
+    /// URIs of contexts loaded from the persisted cache that haven't been revalidated
+    pub persisted_contexts: HashSet<String>,
+    /// URIs of contexts whose tracks are still being loaded
+    pub incomplete_contexts: HashSet<String>,
+    /// URIs of contexts whose tracks have been sorted or reversed,
+    /// so the tracks are no longer in the contexts' original order
+    pub sorted_contexts: HashSet<String>,
+    /// URIs of playlist contexts containing items that are not displayed (local files, episodes, etc)
+    pub filtered_contexts: HashSet<String>,
+    /// URIs of contexts whose tracks' audio features have been requested
+    pub audio_features_contexts: HashSet<String>,
+    /// URIs of contexts whose tracks' audio features are being loaded, mapped to the order
+    /// (if any) to sort the contexts' tracks by once the audio features are loaded
+    pub loading_audio_features: HashMap<String, Option<TrackOrder>>,
+    /// whether the user's saved tracks are still being loaded
+    pub is_loading_saved_tracks: bool,
     pub search: lru::LruCache<String, SearchResults>,
     pub tracks: lru::LruCache<String, Vec<Track>>,
     #[cfg(feature = "lyric-finder")]
@@ -38,12 +69,21 @@ pub struct Caches {
 pub struct BrowseData {
     pub categories: Vec<Category>,
     pub category_playlists: HashMap<String, Vec<Playlist>>,
+    /// public playlists of Spotify users, keyed by the user's id
+    pub user_playlists: HashMap<String, Vec<Playlist>>,
 }
 
 impl Default for Caches {
     fn default() -> Self {
         Self {
             context: lru::LruCache::new(64),
+            persisted_contexts: HashSet::new(),
+            incomplete_contexts: HashSet::new(),
+            sorted_contexts: HashSet::new(),
+            filtered_contexts: HashSet::new(),
+            audio_features_contexts: HashSet::new(),
+            loading_audio_features: HashMap::new(),
+            is_loading_saved_tracks: false,
             search: lru::LruCache::new(64),
             tracks: lru::LruCache::new(64),
             #[cfg(feature = "lyric-finder")]
@@ -62,9 +102,93 @@ impl AppData {
             _ => self.caches.tracks.peek(id),
         }
     }
+
+    /// loads the user data and the context caches persisted in the application's cache folder
+    pub fn load_persisted_data(&mut self, cache_folder: &std::path::Path) -> Result<()> {
+        let path = cache_folder.join(USER_DATA_CACHE_FILE);
+        if path.exists() {
+            self.user_data = serde_json::from_slice(&std::fs::read(path)?)?;
+        }
+
+        let path = cache_folder.join(CONTEXT_CACHE_FILE);
+        if path.exists() {
+            let contexts: Vec<(String, Context)> = serde_json::from_slice(&std::fs::read(path)?)?;
+            // contexts are persisted from the most recently used to the least recently used,
+            // so they need to be put into the cache in a reversed order
+            for (uri, context) in contexts.into_iter().rev() {
+                self.caches.persisted_contexts.insert(uri.clone());
+                self.caches.context.put(uri, context);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// persists the user data and the context caches into the application's cache folder
+    pub fn persist_data(&self, cache_folder: &std::path::Path) -> Result<()> {
+        write_persisted_data(cache_folder, self.serialize_persisted_data()?)
+    }
+
+    /// serializes the data to be persisted, i.e. the user data and the context caches
+    pub fn serialize_persisted_data(&self) -> Result<PersistedData> {
+        // incomplete contexts and contexts whose tracks don't match the contexts' original tracks
+        // are not persisted
+        let contexts = self
+            .caches
+            .context
+            .iter()
+            .filter(|(uri, _)| {
+                !self.caches.incomplete_contexts.contains(*uri)
+                    && !self.caches.sorted_contexts.contains(*uri)
+                    && !self.caches.filtered_contexts.contains(*uri)
+            })
+            .collect::<Vec<_>>();
+        // the user data is not persisted while the saved tracks are partially loaded
+        let user_data = if self.caches.is_loading_saved_tracks {
+            None
+        } else {
+            Some(serde_json::to_vec(&self.user_data)?)
+        };
+        Ok(PersistedData {
+            user_data,
+            contexts: serde_json::to_vec(&contexts)?,
+        })
+    }
+}
+
+/// The serialized data to be persisted into the application's cache folder
+pub struct PersistedData {
+    user_data: Option<Vec<u8>>,
+    contexts: Vec<u8>,
+}
+
+/// writes the serialized data into the application's cache folder
+pub fn write_persisted_data(cache_folder: &std::path::Path, data: PersistedData) -> Result<()> {
+    if let Some(user_data) = data.user_data {
+        write_cache_file(&cache_folder.join(USER_DATA_CACHE_FILE), &user_data)?;
+    }
+    write_cache_file(&cache_folder.join(CONTEXT_CACHE_FILE), &data.contexts)?;
+    Ok(())
+}
+
+/// writes data into a cache file, the data is written into a temporary file first
+/// to avoid leaving a partially written cache file
+fn write_cache_file(path: &std::path::Path, data: &[u8]) -> Result<()> {
+    // use a random temporary file to avoid conflicts between concurrent writes
+    let tmp_path = path.with_extension(format!("{}.tmp", rand::random::<u32>()));
+    std::fs::write(&tmp_path, data)?;
+    std::fs::rename(tmp_path, path)?;
+    Ok(())
 }
 
 impl UserData {
+    /// adds a query to the top of the search history, an existing query is moved to the top
+    pub fn add_search_history(&mut self, query: String) {
+        self.search_history.retain(|q| *q != query);
+        self.search_history.insert(0, query);
+        self.search_history.truncate(SEARCH_HISTORY_LIMIT);
+    }
+
     /// returns a list of playlists that are **possibly** modifiable by user
     pub fn modifiable_playlists(&self) -> Vec<&Playlist> {
         match self.user {
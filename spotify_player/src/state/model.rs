@@ -1,10 +1,12 @@
 pub use rspotify::model as rspotify_model;
-pub use rspotify::model::{AlbumId, ArtistId, Id, PlaylistId, TrackId, UserId};
+pub use rspotify::model::{AlbumId, ArtistId, EpisodeId, Id, PlaylistId, ShowId, TrackId, UserId};
 
 use crate::utils::map_join;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-#[derive(Clone, Debug)]
-/// A Spotify context (playlist, album, artist)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+/// A Spotify context (playlist, album, artist, show)
 pub enum Context {
     Playlist {
         playlist: Playlist,
@@ -18,8 +20,19 @@ pub enum Context {
         artist: Artist,
         top_tracks: Vec<Track>,
         albums: Vec<Album>,
+        #[serde(default)]
+        singles: Vec<Album>,
+        #[serde(default)]
+        compilations: Vec<Album>,
+        /// albums that the artist appears on
+        #[serde(default)]
+        appears_on: Vec<Album>,
         related_artists: Vec<Artist>,
     },
+    Show {
+        show: Show,
+        episodes: Vec<Episode>,
+    },
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -28,6 +41,15 @@ pub enum ContextId {
     Playlist(PlaylistId),
     Album(AlbumId),
     Artist(ArtistId),
+    Show(ShowId),
+}
+
+#[derive(Clone, Debug)]
+/// A Spotify item referred by a Spotify URL or URI
+pub enum SpotifyLink {
+    Track(TrackId),
+    Context(ContextId),
+    User(UserId),
 }
 
 #[derive(Clone, Debug)]
@@ -42,16 +64,45 @@ pub enum Playback {
     URIs(Vec<TrackId>, Option<rspotify_model::Offset>),
 }
 
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 /// Data returned when searching a query using Spotify APIs.
 pub struct SearchResults {
     pub tracks: Vec<Track>,
     pub artists: Vec<Artist>,
     pub albums: Vec<Album>,
     pub playlists: Vec<Playlist>,
+    /// the total number of results of each search type
+    #[serde(skip)]
+    pub totals: HashMap<SearchType, u32>,
+    /// the offset of the next result page of each search type, if there are more results to load
+    #[serde(skip)]
+    pub next_offsets: HashMap<SearchType, u32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A type of Spotify items to search for
+pub enum SearchType {
+    Track,
+    Album,
+    Artist,
+    Playlist,
+}
+
+#[derive(Default, Clone, Debug)]
+/// The user's playback queue
+pub struct PlaybackQueue {
+    pub currently_playing: Option<QueueItem>,
+    pub queue: Vec<QueueItem>,
+}
+
+#[derive(Clone, Debug)]
+/// An item in the user's playback queue
+pub enum QueueItem {
+    Track(Track),
+    Episode(Episode),
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 /// A track order
 pub enum TrackOrder {
     AddedAt,
@@ -59,12 +110,17 @@ pub enum TrackOrder {
     Album,
     Artists,
     Duration,
+    Tempo,
+    Key,
+    Energy,
+    Danceability,
+    Valence,
 }
 
 #[derive(Debug, Clone)]
-/// A Spotify item (track, album, artist, playlist)
+/// A Spotify item (album, artist, playlist) that can be added to user's library.
+/// Tracks are added to (or deleted from) user's liked tracks using separate requests.
 pub enum Item {
-    Track(Track),
     Album(Album),
     Artist(Artist),
     Playlist(Playlist),
@@ -72,7 +128,6 @@ pub enum Item {
 
 #[derive(Debug, Clone)]
 pub enum ItemId {
-    Track(TrackId),
     Album(AlbumId),
     Artist(ArtistId),
     Playlist(PlaylistId),
@@ -83,6 +138,42 @@ pub enum ItemId {
 pub enum SeedItem {
     Track(Track),
     Artist(Artist),
+    Genre(String),
+}
+
+/// the maximum number of seeds in a recommendation query
+pub const MAX_RECOMMENDATION_SEEDS: usize = 5;
+
+#[derive(Debug, Clone, Default)]
+/// A Spotify recommendation query consisting of seeds and tuned track attributes
+pub struct RecommendationQuery {
+    pub seeds: Vec<SeedItem>,
+    pub attributes: Vec<TunedAttribute>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// A track attribute with a target, minimum or maximum value used to tune recommendations
+pub struct TunedAttribute {
+    pub attribute: TunableAttribute,
+    pub bound: AttributeBound,
+    pub value: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A track attribute that can be tuned in a recommendation query
+pub enum TunableAttribute {
+    Energy,
+    Tempo,
+    Popularity,
+    Danceability,
+    Valence,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttributeBound {
+    Target,
+    Min,
+    Max,
 }
 
 /// A simplified version of `rspotify::CurrentPlaybackContext`
@@ -101,7 +192,7 @@ pub struct Device {
     pub name: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 /// A Spotify track
 pub struct Track {
     pub id: TrackId,
@@ -110,31 +201,90 @@ pub struct Track {
     pub album: Option<Album>,
     pub duration: std::time::Duration,
     pub added_at: u64,
+    #[serde(default)]
+    pub audio_features: Option<AudioFeatures>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+/// Audio features of a Spotify track
+pub struct AudioFeatures {
+    /// the track's tempo in beats per minute (BPM)
+    pub tempo: f32,
+    /// the track's key in the standard pitch class notation, `-1` if no key is detected
+    pub key: i32,
+    pub minor: bool,
+    pub energy: f32,
+    pub danceability: f32,
+    pub valence: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 /// A Spotify album
 pub struct Album {
     pub id: AlbumId,
     pub release_date: String,
     pub name: String,
     pub artists: Vec<Artist>,
+    /// the album's type (`album`, `single` or `compilation`)
+    #[serde(default)]
+    pub album_type: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 /// A Spotify artist
 pub struct Artist {
     pub id: ArtistId,
     pub name: String,
+    /// the artist's genres, followers and popularity are only available in a full artist object
+    #[serde(default)]
+    pub genres: Vec<String>,
+    #[serde(default)]
+    pub followers: Option<u32>,
+    #[serde(default)]
+    pub popularity: Option<u32>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 /// A Spotify playlist
 pub struct Playlist {
     pub id: PlaylistId,
     pub collaborative: bool,
     pub name: String,
     pub owner: (String, UserId),
+    pub snapshot_id: String,
+    #[serde(default)]
+    pub public: Option<bool>,
+    /// the playlist's description, which is only available in a full playlist object
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+/// Details of a playlist used to create or edit the playlist.
+/// When editing a playlist, only the specified fields are updated.
+pub struct PlaylistDetails {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub public: Option<bool>,
+    pub collaborative: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+/// A Spotify podcast show
+pub struct Show {
+    pub id: ShowId,
+    pub name: String,
+    pub publisher: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+/// A Spotify podcast episode
+pub struct Episode {
+    pub id: EpisodeId,
+    pub name: String,
+    pub release_date: String,
+    pub duration: std::time::Duration,
+    pub resume_point: Option<rspotify_model::ResumePoint>,
 }
 
 #[derive(Clone, Debug)]
@@ -147,12 +297,31 @@ pub struct Category {
 impl Context {
     /// sorts tracks in the context by a sort oder
     pub fn sort_tracks(&mut self, sort_order: TrackOrder) {
-        self.tracks_mut().sort_by(|x, y| sort_order.compare(x, y));
+        if let Some(tracks) = self.tracks_mut() {
+            tracks.sort_by(|x, y| sort_order.compare(x, y));
+        }
     }
 
-    /// reverses order of tracks in the context
+    /// reverses order of tracks (or episodes for a show) in the context
     pub fn reverse_tracks(&mut self) {
-        self.tracks_mut().reverse();
+        match self {
+            Context::Show { episodes, .. } => episodes.reverse(),
+            _ => {
+                if let Some(tracks) = self.tracks_mut() {
+                    tracks.reverse();
+                }
+            }
+        }
+    }
+
+    /// moves the context's track at position `from` to position `to`
+    pub fn move_track(&mut self, from: usize, to: usize) {
+        if let Some(tracks) = self.tracks_mut() {
+            if from < tracks.len() && to < tracks.len() {
+                let track = tracks.remove(from);
+                tracks.insert(to, track);
+            }
+        }
     }
 
     /// gets the context's description
@@ -181,13 +350,35 @@ impl Context {
                 )
             }
             Context::Artist { ref artist, .. } => {
-                format!("Artist: {}", artist.name)
+                let mut desc = format!("Artist: {}", artist.name);
+                if !artist.genres.is_empty() {
+                    desc += &format!(" | {}", artist.genres.join(", "));
+                }
+                if let Some(followers) = artist.followers {
+                    desc += &format!(" | {followers} followers");
+                }
+                if let Some(popularity) = artist.popularity {
+                    desc += &format!(" | popularity {popularity}");
+                }
+                desc
+            }
+            Context::Show {
+                ref show,
+                ref episodes,
+            } => {
+                format!(
+                    "Show: {} | {} | {} episodes",
+                    show.name,
+                    show.publisher,
+                    episodes.len()
+                )
             }
         }
     }
 
-    /// gets context tracks (immutable)
-    pub fn tracks(&self) -> &Vec<Track> {
+    /// gets context tracks (immutable).
+    /// A show context has no track.
+    pub fn tracks(&self) -> &[Track] {
         match self {
             Context::Album { ref tracks, .. } => tracks,
             Context::Playlist { ref tracks, .. } => tracks,
@@ -195,17 +386,20 @@ impl Context {
                 top_tracks: ref tracks,
                 ..
             } => tracks,
+            Context::Show { .. } => &[],
         }
     }
 
-    /// gets context tracks (mutable)
-    pub fn tracks_mut(&mut self) -> &mut Vec<Track> {
+    /// gets context tracks (mutable).
+    /// A show context has no track.
+    pub fn tracks_mut(&mut self) -> Option<&mut Vec<Track>> {
         match self {
-            Context::Album { tracks, .. } => tracks,
-            Context::Playlist { tracks, .. } => tracks,
+            Context::Album { tracks, .. } => Some(tracks),
+            Context::Playlist { tracks, .. } => Some(tracks),
             Context::Artist {
                 top_tracks: tracks, ..
-            } => tracks,
+            } => Some(tracks),
+            Context::Show { .. } => None,
         }
     }
 }
@@ -216,10 +410,53 @@ impl ContextId {
             Self::Album(id) => id.uri(),
             Self::Artist(id) => id.uri(),
             Self::Playlist(id) => id.uri(),
+            Self::Show(id) => id.uri(),
         }
     }
 }
 
+impl PlaybackQueue {
+    /// gets the queue's items including the currently playing item (if any)
+    fn items(&self) -> impl Iterator<Item = &QueueItem> {
+        self.currently_playing.iter().chain(self.queue.iter())
+    }
+
+    /// gets the queue's tracks including the currently playing track (if any).
+    /// Episodes in the queue are not included.
+    pub fn tracks(&self) -> Vec<&Track> {
+        self.items()
+            .filter_map(|item| match item {
+                QueueItem::Track(track) => Some(track),
+                QueueItem::Episode(_) => None,
+            })
+            .collect()
+    }
+
+    /// gets the number of the queue's upcoming episodes
+    pub fn num_upcoming_episodes(&self) -> usize {
+        self.queue
+            .iter()
+            .filter(|item| matches!(item, QueueItem::Episode(_)))
+            .count()
+    }
+
+    /// gets the number of skips to play the `id`-th track returned by `tracks`.
+    /// Episodes before the track are also skipped.
+    pub fn skips_to_track(&self, id: usize) -> Option<usize> {
+        let position = self
+            .items()
+            .enumerate()
+            .filter(|(_, item)| matches!(item, QueueItem::Track(_)))
+            .nth(id)?
+            .0;
+        Some(if self.currently_playing.is_some() {
+            position
+        } else {
+            position + 1
+        })
+    }
+}
+
 impl TrackOrder {
     pub fn compare(&self, x: &Track, y: &Track) -> std::cmp::Ordering {
         match *self {
@@ -228,6 +465,109 @@ impl TrackOrder {
             Self::Album => x.album_info().cmp(&y.album_info()),
             Self::Duration => x.duration.cmp(&y.duration),
             Self::Artists => x.artists_info().cmp(&y.artists_info()),
+            Self::Tempo => Self::compare_audio_features(x, y, |f| f.tempo),
+            Self::Key => Self::compare_audio_features(x, y, |f| (f.key, f.minor)),
+            Self::Energy => Self::compare_audio_features(x, y, |f| f.energy),
+            Self::Danceability => Self::compare_audio_features(x, y, |f| f.danceability),
+            Self::Valence => Self::compare_audio_features(x, y, |f| f.valence),
+        }
+    }
+
+    /// checks if the order requires tracks' audio features
+    pub fn requires_audio_features(&self) -> bool {
+        matches!(
+            self,
+            Self::Tempo | Self::Key | Self::Energy | Self::Danceability | Self::Valence
+        )
+    }
+
+    /// compares two tracks by a field of the tracks' audio features,
+    /// tracks without audio features are placed after the other tracks
+    fn compare_audio_features<T, F>(x: &Track, y: &Track, f: F) -> std::cmp::Ordering
+    where
+        T: PartialOrd,
+        F: Fn(&AudioFeatures) -> T,
+    {
+        match (x.audio_features.as_ref(), y.audio_features.as_ref()) {
+            (Some(x), Some(y)) => f(x).partial_cmp(&f(y)).unwrap_or(std::cmp::Ordering::Equal),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        }
+    }
+}
+
+impl SearchResults {
+    /// merges results of another search into the current results
+    pub fn merge(&mut self, other: SearchResults) {
+        self.tracks.extend(other.tracks);
+        self.artists.extend(other.artists);
+        self.albums.extend(other.albums);
+        self.playlists.extend(other.playlists);
+        self.totals.extend(other.totals);
+        self.next_offsets.extend(other.next_offsets);
+    }
+
+    /// gets the number of loaded results of a search type
+    pub fn len(&self, search_type: SearchType) -> usize {
+        match search_type {
+            SearchType::Track => self.tracks.len(),
+            SearchType::Album => self.albums.len(),
+            SearchType::Artist => self.artists.len(),
+            SearchType::Playlist => self.playlists.len(),
+        }
+    }
+}
+
+impl SearchType {
+    /// Spotify's search field filters, e.g. `artist:Adele` or `tag:new`
+    pub const FIELD_FILTERS: [&'static str; 6] =
+        ["track", "artist", "album", "year", "genre", "tag"];
+
+    /// checks if a search query can be used to search for items of the type.
+    /// A type is searchable if it supports all the field filters in the query.
+    pub fn is_searchable_by(&self, query: &str) -> bool {
+        search_field_filters(query)
+            .into_iter()
+            .all(|filter| match filter {
+                "track" => *self == Self::Track,
+                "artist" | "album" | "year" => *self != Self::Playlist,
+                "genre" => matches!(self, Self::Track | Self::Artist),
+                "tag" => *self == Self::Album,
+                _ => true,
+            })
+    }
+}
+
+/// gets the field filters used in a search query. Field filters inside quotes are ignored.
+fn search_field_filters(query: &str) -> Vec<&str> {
+    let mut filters = vec![];
+    let mut in_quotes = false;
+    let mut word_start = true;
+    for (i, c) in query.char_indices() {
+        if c == '"' {
+            in_quotes = !in_quotes;
+        }
+        if !in_quotes && word_start {
+            let filter = SearchType::FIELD_FILTERS
+                .into_iter()
+                .find(|f| query[i..].starts_with(&format!("{f}:")));
+            if let Some(filter) = filter {
+                filters.push(filter);
+            }
+        }
+        word_start = c.is_whitespace();
+    }
+    filters
+}
+
+impl From<SearchType> for rspotify_model::SearchType {
+    fn from(t: SearchType) -> Self {
+        match t {
+            SearchType::Track => Self::Track,
+            SearchType::Album => Self::Album,
+            SearchType::Artist => Self::Artist,
+            SearchType::Playlist => Self::Playlist,
         }
     }
 }
@@ -238,6 +578,105 @@ impl SeedItem {
         match self {
             Self::Track(track) => track.id.uri(),
             Self::Artist(artist) => artist.id.uri(),
+            Self::Genre(genre) => format!("genre:{genre}"),
+        }
+    }
+
+    /// gets the name of the seed item
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Track(track) => &track.name,
+            Self::Artist(artist) => &artist.name,
+            Self::Genre(genre) => genre,
+        }
+    }
+}
+
+impl RecommendationQuery {
+    /// creates a recommendation query from a single seed without tuned attributes
+    pub fn from_seed(seed: SeedItem) -> Self {
+        Self {
+            seeds: vec![seed],
+            attributes: vec![],
+        }
+    }
+
+    /// gets the id of the query's recommendation tracks page,
+    /// which is also used to cache the query's recommendation tracks
+    pub fn id(&self) -> String {
+        let seeds = self.seeds.iter().map(|s| s.uri()).collect::<Vec<_>>();
+        let mut id = format!("recommendations::{}", seeds.join(","));
+        if !self.attributes.is_empty() {
+            let attributes = self.attributes.iter().map(|a| a.to_string());
+            id.push('?');
+            id.push_str(&attributes.collect::<Vec<_>>().join("&"));
+        }
+        id
+    }
+
+    /// gets the description of the query's recommendation tracks page
+    pub fn desc(&self) -> String {
+        format!("{} Radio", map_join(&self.seeds, |s| s.name(), ", "))
+    }
+}
+
+impl TunableAttribute {
+    /// all the tunable track attributes
+    pub const ALL: [Self; 5] = [
+        Self::Energy,
+        Self::Tempo,
+        Self::Popularity,
+        Self::Danceability,
+        Self::Valence,
+    ];
+
+    /// checks if a value is in the attribute's valid range. Tempo is a non-negative number,
+    /// popularity ranges from 0 to 100 and the other attributes range from 0 to 1
+    pub fn is_valid_value(&self, value: f32) -> bool {
+        match self {
+            Self::Tempo => value.is_finite() && value >= 0.0,
+            Self::Popularity => (0.0..=100.0).contains(&value),
+            Self::Energy | Self::Danceability | Self::Valence => (0.0..=1.0).contains(&value),
+        }
+    }
+}
+
+impl std::fmt::Display for TunedAttribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bound = match self.bound {
+            AttributeBound::Target => "target",
+            AttributeBound::Min => "min",
+            AttributeBound::Max => "max",
+        };
+        let attribute = format!("{:?}", self.attribute).to_lowercase();
+        write!(f, "{bound}_{attribute}={}", self.value)
+    }
+}
+
+impl From<TunedAttribute> for rspotify_model::RecommendationsAttribute {
+    fn from(a: TunedAttribute) -> Self {
+        use rspotify_model::RecommendationsAttribute::*;
+        use AttributeBound::*;
+        use TunableAttribute::*;
+
+        // Spotify only accepts integer popularity values
+        let popularity = a.value.round() as i32;
+        match (a.attribute, a.bound) {
+            (Energy, Target) => TargetEnergy(a.value),
+            (Energy, Min) => MinEnergy(a.value),
+            (Energy, Max) => MaxEnergy(a.value),
+            (Tempo, Target) => TargetTempo(a.value),
+            (Tempo, Min) => MinTempo(a.value),
+            (Tempo, Max) => MaxTempo(a.value),
+            (Popularity, Target) => TargetPopularity(popularity),
+            (Popularity, Min) => MinPopularity(popularity),
+            (Popularity, Max) => MaxPopularity(popularity),
+            (Danceability, Target) => TargetDanceability(a.value),
+            (Danceability, Min) => MinDanceability(a.value),
+            (Danceability, Max) => MaxDanceability(a.value),
+            (Valence, Target) => TargetValence(a.value),
+            (Valence, Min) => MinValence(a.value),
+            (Valence, Max) => MaxValence(a.value),
         }
     }
 }
@@ -275,6 +714,7 @@ impl Track {
             album: None,
             duration: track.duration,
             added_at: 0,
+            audio_features: None,
         })
     }
 
@@ -287,10 +727,40 @@ impl Track {
             album: Album::try_from_simplified_album(track.album),
             duration: track.duration,
             added_at: 0,
+            audio_features: None,
         })
     }
 }
 
+impl AudioFeatures {
+    /// gets the track's key information, e.g. `C#` (C-sharp major) or `Am` (A minor)
+    pub fn key_info(&self) -> String {
+        const PITCH_CLASSES: [&str; 12] = [
+            "C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B",
+        ];
+        match usize::try_from(self.key)
+            .ok()
+            .and_then(|k| PITCH_CLASSES.get(k))
+        {
+            Some(pitch) => format!("{pitch}{}", if self.minor { "m" } else { "" }),
+            None => String::new(),
+        }
+    }
+}
+
+impl From<rspotify_model::AudioFeatures> for AudioFeatures {
+    fn from(features: rspotify_model::AudioFeatures) -> Self {
+        Self {
+            tempo: features.tempo,
+            key: features.key,
+            minor: features.mode == rspotify_model::Modality::Minor,
+            energy: features.energy,
+            danceability: features.danceability,
+            valence: features.valence,
+        }
+    }
+}
+
 impl std::fmt::Display for Track {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
@@ -311,8 +781,14 @@ impl Album {
             name: album.name,
             release_date: album.release_date.unwrap_or_default(),
             artists: from_simplified_artists_to_artists(album.artists),
+            album_type: album.album_type.unwrap_or_default(),
         })
     }
+
+    /// gets the album's release year
+    pub fn year(&self) -> &str {
+        self.release_date.get(..4).unwrap_or(&self.release_date)
+    }
 }
 
 impl From<rspotify_model::FullAlbum> for Album {
@@ -322,6 +798,7 @@ impl From<rspotify_model::FullAlbum> for Album {
             id: album.id,
             release_date: album.release_date,
             artists: from_simplified_artists_to_artists(album.artists),
+            album_type: album.album_type.as_ref().to_string(),
         }
     }
 }
@@ -343,6 +820,9 @@ impl Artist {
         Some(Self {
             id: artist.id?,
             name: artist.name,
+            genres: vec![],
+            followers: None,
+            popularity: None,
         })
     }
 }
@@ -352,6 +832,9 @@ impl From<rspotify_model::FullArtist> for Artist {
         Self {
             name: artist.name,
             id: artist.id,
+            genres: artist.genres,
+            followers: Some(artist.followers.total),
+            popularity: Some(artist.popularity),
         }
     }
 }
@@ -383,6 +866,9 @@ impl From<rspotify_model::SimplifiedPlaylist> for Playlist {
                 playlist.owner.display_name.unwrap_or_default(),
                 playlist.owner.id,
             ),
+            snapshot_id: playlist.snapshot_id,
+            public: playlist.public,
+            description: None,
         }
     }
 }
@@ -397,6 +883,27 @@ impl From<rspotify_model::FullPlaylist> for Playlist {
                 playlist.owner.display_name.unwrap_or_default(),
                 playlist.owner.id,
             ),
+            snapshot_id: playlist.snapshot_id,
+            public: playlist.public,
+            description: Some(playlist.description.unwrap_or_default()),
+        }
+    }
+}
+
+impl Playlist {
+    /// updates the playlist's details
+    pub fn update_details(&mut self, details: &PlaylistDetails) {
+        if let Some(ref name) = details.name {
+            self.name = name.clone();
+        }
+        if let Some(ref description) = details.description {
+            self.description = Some(description.clone());
+        }
+        if let Some(public) = details.public {
+            self.public = Some(public);
+        }
+        if let Some(collaborative) = details.collaborative {
+            self.collaborative = collaborative;
         }
     }
 }
@@ -407,6 +914,77 @@ impl std::fmt::Display for Playlist {
     }
 }
 
+impl From<rspotify_model::SimplifiedShow> for Show {
+    fn from(show: rspotify_model::SimplifiedShow) -> Self {
+        Self {
+            id: show.id,
+            name: show.name,
+            publisher: show.publisher,
+        }
+    }
+}
+
+impl From<rspotify_model::FullShow> for Show {
+    fn from(show: rspotify_model::FullShow) -> Self {
+        Self {
+            id: show.id,
+            name: show.name,
+            publisher: show.publisher,
+        }
+    }
+}
+
+impl std::fmt::Display for Show {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} • {}", self.name, self.publisher)
+    }
+}
+
+impl Episode {
+    /// gets the episode's playing progress information, which is either
+    /// the played state or the remaining time of a partially played episode
+    pub fn progress_info(&self) -> String {
+        match self.resume_point {
+            Some(ref p) if p.fully_played => "played".to_string(),
+            Some(ref p) if !p.resume_position.is_zero() => format!(
+                "{} left",
+                crate::utils::format_duration(self.duration.saturating_sub(p.resume_position))
+            ),
+            _ => String::new(),
+        }
+    }
+}
+
+impl From<rspotify_model::SimplifiedEpisode> for Episode {
+    fn from(episode: rspotify_model::SimplifiedEpisode) -> Self {
+        Self {
+            id: episode.id,
+            name: episode.name,
+            release_date: episode.release_date,
+            duration: episode.duration,
+            resume_point: episode.resume_point,
+        }
+    }
+}
+
+impl From<rspotify_model::FullEpisode> for Episode {
+    fn from(episode: rspotify_model::FullEpisode) -> Self {
+        Self {
+            id: episode.id,
+            name: episode.name,
+            release_date: episode.release_date,
+            duration: episode.duration,
+            resume_point: episode.resume_point,
+        }
+    }
+}
+
+impl std::fmt::Display for Episode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
 impl From<rspotify_model::category::Category> for Category {
     fn from(c: rspotify_model::category::Category) -> Self {
         Self {
@@ -421,3 +999,59 @@ impl std::fmt::Display for Category {
         write!(f, "{}", self.name)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{search_field_filters, SearchType};
+
+    #[test]
+    fn search_field_filters_of_plain_query() {
+        assert!(search_field_filters("").is_empty());
+        assert!(search_field_filters("never gonna give you up").is_empty());
+    }
+
+    #[test]
+    fn search_field_filters_with_quoted_values() {
+        assert_eq!(search_field_filters(r#"artist:"foo bar""#), vec!["artist"]);
+        assert_eq!(
+            search_field_filters(r#"artist:"foo bar" year:1990-2000"#),
+            vec!["artist", "year"]
+        );
+        // field filters inside quotes are part of the searched text
+        assert!(search_field_filters(r#""artist:foo""#).is_empty());
+        assert!(search_field_filters(r#"song "foo album:bar""#).is_empty());
+    }
+
+    #[test]
+    fn search_field_filters_ignore_unknown_fields() {
+        assert!(search_field_filters("foo:bar isrc:123").is_empty());
+        assert_eq!(search_field_filters("foo:bar genre:rock"), vec!["genre"]);
+        // a field filter must start a word
+        assert!(search_field_filters("myartist:foo").is_empty());
+        assert!(search_field_filters("artist foo").is_empty());
+    }
+
+    #[test]
+    fn search_types_searchable_by_query() {
+        let query = "track:foo artist:bar";
+        assert!(SearchType::Track.is_searchable_by(query));
+        assert!(!SearchType::Album.is_searchable_by(query));
+        assert!(!SearchType::Artist.is_searchable_by(query));
+        assert!(!SearchType::Playlist.is_searchable_by(query));
+
+        assert!(SearchType::Album.is_searchable_by("tag:new"));
+        assert!(!SearchType::Track.is_searchable_by("tag:new"));
+        assert!(SearchType::Artist.is_searchable_by("genre:jazz"));
+        assert!(!SearchType::Album.is_searchable_by("genre:jazz"));
+
+        for t in [
+            SearchType::Track,
+            SearchType::Album,
+            SearchType::Artist,
+            SearchType::Playlist,
+        ] {
+            assert!(t.is_searchable_by("foo:bar baz"));
+            assert!(t.is_searchable_by(r#""artist:foo""#));
+        }
+    }
+}
@@ -10,6 +10,11 @@ pub struct PlayerState {
 
     pub playback: Option<rspotify_model::CurrentPlaybackContext>,
     pub playback_last_updated_time: Option<std::time::Instant>,
+
+    pub queue: Option<PlaybackQueue>,
+
+    /// the integrated device's ID, whose playback is updated by the integrated player's events
+    pub integrated_device_id: Option<String>,
 }
 
 impl PlayerState {
@@ -23,6 +28,14 @@ impl PlayerState {
         })
     }
 
+    /// checks if the current playback is played on the integrated device
+    pub fn is_playing_on_integrated_device(&self) -> bool {
+        match (&self.playback, &self.integrated_device_id) {
+            (Some(playback), Some(id)) => playback.device.id.as_ref() == Some(id),
+            _ => false,
+        }
+    }
+
     /// gets the current playing track
     pub fn current_playing_track(&self) -> Option<&rspotify_model::FullTrack> {
         match self.playback {
@@ -34,11 +47,24 @@ impl PlayerState {
         }
     }
 
+    /// gets the duration of the current playing item (track or episode)
+    pub fn current_playing_item_duration(&self) -> Option<std::time::Duration> {
+        match self.playback.as_ref()?.item.as_ref()? {
+            rspotify::model::PlayableItem::Track(track) => Some(track.duration),
+            rspotify::model::PlayableItem::Episode(episode) => Some(episode.duration),
+        }
+    }
+
     #[cfg(feature = "image")]
-    /// gets the current playing track's album cover URL
-    pub fn current_playing_track_album_cover_url(&self) -> Option<&str> {
-        self.current_playing_track()
-            .and_then(utils::get_track_album_image_url)
+    /// gets the current playing item's cover URL, which is either
+    /// the track's album cover or the episode's cover
+    pub fn current_playing_item_cover_url(&self) -> Option<&str> {
+        match self.playback.as_ref()?.item.as_ref()? {
+            rspotify::model::PlayableItem::Track(track) => utils::get_track_album_image_url(track),
+            rspotify::model::PlayableItem::Episode(episode) => {
+                utils::get_episode_image_url(episode)
+            }
+        }
     }
 
     /// gets the current playback progress
@@ -74,6 +100,9 @@ impl PlayerState {
                         rspotify_model::Type::Artist => {
                             Some(ContextId::Artist(ArtistId::from_uri(&uri).ok()?))
                         }
+                        rspotify_model::Type::Show => {
+                            Some(ContextId::Show(ShowId::from_uri(&uri).ok()?))
+                        }
                         _ => None,
                     }
                 }
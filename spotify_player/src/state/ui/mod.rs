@@ -1,4 +1,4 @@
-use crate::{config, key};
+use crate::{config, key, state::model::Track};
 
 pub type UIStateGuard<'a> = parking_lot::MutexGuard<'a, UIState>;
 
@@ -18,6 +18,12 @@ pub struct UIState {
     pub history: Vec<PageState>,
     pub popup: Option<PopupState>,
 
+    /// tracks marked in the current page, which are used to apply an action to multiple tracks
+    pub marked_tracks: Vec<Track>,
+
+    /// the recommendations builder's state, which is kept between openings of the builder popup
+    pub recommendations_builder: RecommendationsBuilderState,
+
     /// The rectangle representing the playback progress bar,
     /// which is mainly used to handle mouse click events (for seeking command)
     pub playback_progress_bar_rect: tui::layout::Rect,
@@ -38,6 +44,7 @@ impl UIState {
     pub fn create_new_page(&mut self, page: PageState) {
         self.history.push(page);
         self.popup = None;
+        self.marked_tracks.clear();
     }
 
     /// Returns whether there exists a focused popup.
@@ -61,6 +68,21 @@ impl UIState {
         }
     }
 
+    /// marks a track if the track is not marked, otherwise unmarks the track
+    pub fn toggle_marked_track(&mut self, track: &Track) {
+        match self.marked_tracks.iter().position(|t| t.id == track.id) {
+            Some(id) => {
+                self.marked_tracks.remove(id);
+            }
+            None => self.marked_tracks.push(track.clone()),
+        }
+    }
+
+    /// checks if a track is marked
+    pub fn is_marked_track(&self, track: &Track) -> bool {
+        self.marked_tracks.iter().any(|t| t.id == track.id)
+    }
+
     /// checks if a string matches a given query
     fn is_match(s: &str, query: &str) -> bool {
         query
@@ -81,6 +103,10 @@ impl Default for UIState {
             }],
             popup: None,
 
+            marked_tracks: vec![],
+
+            recommendations_builder: RecommendationsBuilderState::default(),
+
             playback_progress_bar_rect: tui::layout::Rect::default(),
 
             #[cfg(feature = "image")]
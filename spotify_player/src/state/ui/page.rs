@@ -20,6 +20,11 @@ pub enum PageState {
         id: String,
         title: String,
         desc: String,
+        /// the recommendation query of a recommendation tracks page, used to refresh the page
+        query: Option<RecommendationQuery>,
+        state: TableState,
+    },
+    Queue {
         state: TableState,
     },
     #[cfg(feature = "lyric-finder")]
@@ -38,6 +43,7 @@ pub enum PageType {
     Context,
     Search,
     Tracks,
+    Queue,
     Browse,
     #[cfg(feature = "lyric-finder")]
     Lyric,
@@ -48,6 +54,7 @@ pub struct LibraryPageUIState {
     pub playlist_list: ListState,
     pub saved_album_list: ListState,
     pub followed_artist_list: ListState,
+    pub saved_show_list: ListState,
     pub focus: LibraryFocusState,
 }
 
@@ -58,6 +65,10 @@ pub struct SearchPageUIState {
     pub artist_list: ListState,
     pub playlist_list: ListState,
     pub focus: SearchFocusState,
+    /// the type of search results shown in the page, `None` to show results of all types
+    pub search_type: Option<SearchType>,
+    /// the position in the search history of the query recalled into the search input
+    pub history_index: Option<usize>,
 }
 
 #[derive(Clone, Debug)]
@@ -77,9 +88,15 @@ pub enum ContextPageUIState {
     Artist {
         top_track_table: TableState,
         album_list: ListState,
+        single_list: ListState,
+        compilation_list: ListState,
+        appears_on_list: ListState,
         related_artist_list: ListState,
         focus: ArtistFocusState,
     },
+    Show {
+        episode_table: TableState,
+    },
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
@@ -87,12 +104,16 @@ pub enum LibraryFocusState {
     Playlists,
     SavedAlbums,
     FollowedArtists,
+    SavedShows,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum ArtistFocusState {
     TopTracks,
     Albums,
+    Singles,
+    Compilations,
+    AppearsOn,
     RelatedArtists,
 }
 
@@ -114,6 +135,11 @@ pub enum BrowsePageUIState {
         category: Category,
         state: ListState,
     },
+    /// public playlists of a Spotify user
+    UserPlaylists {
+        user_id: UserId,
+        state: ListState,
+    },
 }
 
 pub enum MutableWindowState<'a> {
@@ -129,6 +155,7 @@ impl PageState {
             PageState::Context { .. } => PageType::Context,
             PageState::Search { .. } => PageType::Search,
             PageState::Tracks { .. } => PageType::Tracks,
+            PageState::Queue { .. } => PageType::Queue,
             PageState::Browse { .. } => PageType::Browse,
             #[cfg(feature = "lyric-finder")]
             PageState::Lyric { .. } => PageType::Lyric,
@@ -157,6 +184,7 @@ impl PageState {
                         playlist_list,
                         saved_album_list,
                         followed_artist_list,
+                        saved_show_list,
                         focus,
                     },
             } => Some(match focus {
@@ -165,6 +193,7 @@ impl PageState {
                 LibraryFocusState::FollowedArtists => {
                     MutableWindowState::List(followed_artist_list)
                 }
+                LibraryFocusState::SavedShows => MutableWindowState::List(saved_show_list),
             }),
             Self::Search {
                 state:
@@ -174,6 +203,7 @@ impl PageState {
                         artist_list,
                         playlist_list,
                         focus,
+                        ..
                     },
                 ..
             } => match focus {
@@ -191,20 +221,31 @@ impl PageState {
                 ContextPageUIState::Artist {
                     top_track_table,
                     album_list,
+                    single_list,
+                    compilation_list,
+                    appears_on_list,
                     related_artist_list,
                     focus,
                 } => match focus {
                     ArtistFocusState::TopTracks => MutableWindowState::Table(top_track_table),
                     ArtistFocusState::Albums => MutableWindowState::List(album_list),
+                    ArtistFocusState::Singles => MutableWindowState::List(single_list),
+                    ArtistFocusState::Compilations => MutableWindowState::List(compilation_list),
+                    ArtistFocusState::AppearsOn => MutableWindowState::List(appears_on_list),
                     ArtistFocusState::RelatedArtists => {
                         MutableWindowState::List(related_artist_list)
                     }
                 },
+                ContextPageUIState::Show { episode_table } => {
+                    MutableWindowState::Table(episode_table)
+                }
             }),
             Self::Tracks { state, .. } => Some(MutableWindowState::Table(state)),
+            Self::Queue { state } => Some(MutableWindowState::Table(state)),
             Self::Browse { state } => match state {
                 BrowsePageUIState::CategoryList { state } => Some(MutableWindowState::List(state)),
-                BrowsePageUIState::CategoryPlaylistList { state, .. } => {
+                BrowsePageUIState::CategoryPlaylistList { state, .. }
+                | BrowsePageUIState::UserPlaylists { state, .. } => {
                     Some(MutableWindowState::List(state))
                 }
             },
@@ -220,6 +261,7 @@ impl LibraryPageUIState {
             playlist_list: utils::new_list_state(),
             saved_album_list: utils::new_list_state(),
             followed_artist_list: utils::new_list_state(),
+            saved_show_list: utils::new_list_state(),
             focus: LibraryFocusState::Playlists,
         }
     }
@@ -233,6 +275,58 @@ impl SearchPageUIState {
             artist_list: utils::new_list_state(),
             playlist_list: utils::new_list_state(),
             focus: SearchFocusState::Input,
+            search_type: None,
+            history_index: None,
+        }
+    }
+
+    /// cycles the type of search results shown in the page,
+    /// the focus is moved to the window of the new type (if not focusing on the search input)
+    pub fn cycle_search_type(&mut self) {
+        self.search_type = match self.search_type {
+            None => Some(SearchType::Track),
+            Some(SearchType::Track) => Some(SearchType::Album),
+            Some(SearchType::Album) => Some(SearchType::Artist),
+            Some(SearchType::Artist) => Some(SearchType::Playlist),
+            Some(SearchType::Playlist) => None,
+        };
+        if self.focus != SearchFocusState::Input {
+            self.focus = self
+                .search_type
+                .map(SearchFocusState::from)
+                .unwrap_or(SearchFocusState::Tracks);
+        }
+    }
+
+    /// moves the focus between the search input and the window of a search type
+    fn toggle_input_focus(&mut self, search_type: SearchType) {
+        self.focus = match self.focus {
+            SearchFocusState::Input => search_type.into(),
+            _ => SearchFocusState::Input,
+        };
+    }
+}
+
+impl SearchFocusState {
+    /// gets the search type of the focused window (if any)
+    pub fn search_type(&self) -> Option<SearchType> {
+        match self {
+            Self::Input => None,
+            Self::Tracks => Some(SearchType::Track),
+            Self::Albums => Some(SearchType::Album),
+            Self::Artists => Some(SearchType::Artist),
+            Self::Playlists => Some(SearchType::Playlist),
+        }
+    }
+}
+
+impl From<SearchType> for SearchFocusState {
+    fn from(t: SearchType) -> Self {
+        match t {
+            SearchType::Track => Self::Tracks,
+            SearchType::Album => Self::Albums,
+            SearchType::Artist => Self::Artists,
+            SearchType::Playlist => Self::Playlists,
         }
     }
 }
@@ -254,10 +348,19 @@ impl ContextPageUIState {
         Self::Artist {
             top_track_table: utils::new_table_state(),
             album_list: utils::new_list_state(),
+            single_list: utils::new_list_state(),
+            compilation_list: utils::new_list_state(),
+            appears_on_list: utils::new_list_state(),
             related_artist_list: utils::new_list_state(),
             focus: ArtistFocusState::TopTracks,
         }
     }
+
+    pub fn new_show() -> Self {
+        Self::Show {
+            episode_table: utils::new_table_state(),
+        }
+    }
 }
 
 impl<'a> MutableWindowState<'a> {
@@ -284,10 +387,7 @@ pub trait Focusable {
 impl Focusable for PageState {
     fn next(&mut self) {
         match self {
-            Self::Search {
-                state: SearchPageUIState { focus, .. },
-                ..
-            } => focus.next(),
+            Self::Search { state, .. } => state.next(),
             Self::Library {
                 state: LibraryPageUIState { focus, .. },
                 ..
@@ -307,10 +407,7 @@ impl Focusable for PageState {
 
     fn previous(&mut self) {
         match self {
-            Self::Search {
-                state: SearchPageUIState { focus, .. },
-                ..
-            } => focus.previous(),
+            Self::Search { state, .. } => state.previous(),
             Self::Library {
                 state: LibraryPageUIState { focus, .. },
                 ..
@@ -329,6 +426,23 @@ impl Focusable for PageState {
     }
 }
 
+impl Focusable for SearchPageUIState {
+    fn next(&mut self) {
+        match self.search_type {
+            // only the search input and the window of the search type are shown
+            Some(search_type) => self.toggle_input_focus(search_type),
+            None => self.focus.next(),
+        }
+    }
+
+    fn previous(&mut self) {
+        match self.search_type {
+            Some(search_type) => self.toggle_input_focus(search_type),
+            None => self.focus.previous(),
+        }
+    }
+}
+
 macro_rules! impl_focusable {
 	($struct:ty, $([$field:ident, $next_field:ident]),+) => {
 		impl Focusable for $struct {
@@ -355,13 +469,17 @@ impl_focusable!(
     LibraryFocusState,
     [Playlists, SavedAlbums],
     [SavedAlbums, FollowedArtists],
-    [FollowedArtists, Playlists]
+    [FollowedArtists, SavedShows],
+    [SavedShows, Playlists]
 );
 
 impl_focusable!(
     ArtistFocusState,
     [TopTracks, Albums],
-    [Albums, RelatedArtists],
+    [Albums, Singles],
+    [Singles, Compilations],
+    [Compilations, AppearsOn],
+    [AppearsOn, RelatedArtists],
     [RelatedArtists, TopTracks]
 );
 
@@ -1,10 +1,15 @@
+use super::Focusable;
 use crate::{command, state::model::*};
 use tui::widgets::ListState;
 
 #[derive(Debug)]
 pub enum PopupState {
-    CommandHelp { scroll_offset: usize },
-    Search { query: String },
+    CommandHelp {
+        scroll_offset: usize,
+    },
+    Search {
+        query: String,
+    },
     UserPlaylistList(PlaylistPopupAction, ListState),
     UserFollowedArtistList(ListState),
     UserSavedAlbumList(ListState),
@@ -12,21 +17,86 @@ pub enum PopupState {
     ArtistList(Vec<Artist>, ListState),
     ThemeList(Vec<crate::config::Theme>, ListState),
     ActionList(ActionListItem, ListState),
+    PlaylistEditor(PlaylistEditorState),
+    /// a popup to build a recommendation query, whose state is
+    /// stored in `UIState::recommendations_builder`
+    RecommendationsBuilder,
+    /// a popup to input the new position of a playlist's track
+    PlaylistTrackPosition {
+        playlist_id: PlaylistId,
+        track_index: usize,
+        position: String,
+    },
+    /// a popup to input a Spotify URL or URI to open
+    OpenLink {
+        link: String,
+    },
+    /// a popup to show a message, which is closed by any key
+    Message(String),
 }
 
 #[derive(Debug)]
 pub enum ActionListItem {
     Track(Track, Vec<command::TrackAction>),
+    /// multiple (marked) tracks
+    Tracks(Vec<Track>, Vec<command::TrackAction>),
     Artist(Artist, Vec<command::ArtistAction>),
     Album(Album, Vec<command::AlbumAction>),
     Playlist(Playlist, Vec<command::PlaylistAction>),
 }
 
+#[derive(Debug)]
+/// The state of a popup for creating or editing a playlist
+pub struct PlaylistEditorState {
+    /// the edited playlist, `None` if creating a new playlist
+    pub playlist: Option<Playlist>,
+    pub name: String,
+    /// the playlist's description, `None` if the edited playlist's description is unknown
+    /// and hasn't been edited
+    pub description: Option<String>,
+    /// whether the playlist is public, `None` if it's unknown and hasn't been edited
+    pub public: Option<bool>,
+    pub collaborative: bool,
+    /// tracks added to the playlist after it's created
+    pub tracks: Vec<TrackId>,
+    pub focus: PlaylistEditorField,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// An input field of a playlist editor popup
+pub enum PlaylistEditorField {
+    Name,
+    Description,
+    Public,
+    Collaborative,
+}
+
+#[derive(Debug, Default)]
+/// The state of a popup for building a recommendation query
+pub struct RecommendationsBuilderState {
+    pub seeds: Vec<SeedItem>,
+    /// genre seeds separated by commas
+    pub genres: String,
+    /// inputs of the tuned track attributes. Each input is a whitespace-separated list
+    /// of `{value}` (target), `>{value}` (minimum) or `<{value}` (maximum) values
+    pub attributes: Vec<(TunableAttribute, String)>,
+    pub focus: RecommendationsBuilderField,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+/// An input field of a recommendations builder popup
+pub enum RecommendationsBuilderField {
+    #[default]
+    Seeds,
+    Genres,
+    Attribute(TunableAttribute),
+}
+
 /// An action on an item in a playlist popup list
 #[derive(Debug)]
 pub enum PlaylistPopupAction {
     Browse,
-    AddTrack(TrackId),
+    AddTracks(Vec<TrackId>),
 }
 
 impl PopupState {
@@ -40,7 +110,13 @@ impl PopupState {
             Self::ArtistList(.., list_state) => Some(list_state),
             Self::ThemeList(.., list_state) => Some(list_state),
             Self::ActionList(.., list_state) => Some(list_state),
-            Self::CommandHelp { .. } | Self::Search { .. } => None,
+            Self::CommandHelp { .. }
+            | Self::Search { .. }
+            | Self::PlaylistEditor(_)
+            | Self::RecommendationsBuilder
+            | Self::PlaylistTrackPosition { .. }
+            | Self::OpenLink { .. }
+            | Self::Message(_) => None,
         }
     }
 
@@ -54,7 +130,13 @@ impl PopupState {
             Self::ArtistList(.., list_state) => Some(list_state),
             Self::ThemeList(.., list_state) => Some(list_state),
             Self::ActionList(.., list_state) => Some(list_state),
-            Self::CommandHelp { .. } | Self::Search { .. } => None,
+            Self::CommandHelp { .. }
+            | Self::Search { .. }
+            | Self::PlaylistEditor(_)
+            | Self::RecommendationsBuilder
+            | Self::PlaylistTrackPosition { .. }
+            | Self::OpenLink { .. }
+            | Self::Message(_) => None,
         }
     }
 
@@ -75,28 +157,259 @@ impl PopupState {
     }
 }
 
+impl PlaylistEditorState {
+    /// creates a new editor state for creating a new playlist
+    pub fn new() -> Self {
+        Self {
+            playlist: None,
+            name: String::new(),
+            description: Some(String::new()),
+            public: Some(true),
+            collaborative: false,
+            tracks: vec![],
+            focus: PlaylistEditorField::Name,
+        }
+    }
+
+    /// creates a new editor state for saving tracks as a new playlist
+    pub fn with_tracks(name: String, tracks: Vec<TrackId>) -> Self {
+        Self {
+            name,
+            tracks,
+            ..Self::new()
+        }
+    }
+
+    /// creates a new editor state for editing an existing playlist
+    pub fn from_playlist(playlist: Playlist) -> Self {
+        Self {
+            name: playlist.name.clone(),
+            description: playlist.description.clone(),
+            public: playlist.public,
+            collaborative: playlist.collaborative,
+            tracks: vec![],
+            focus: PlaylistEditorField::Name,
+            playlist: Some(playlist),
+        }
+    }
+
+    /// gets the playlist details from the editor's inputs.
+    /// When editing a playlist, only details edited by the user are returned.
+    pub fn details(&self) -> PlaylistDetails {
+        let details = PlaylistDetails {
+            name: Some(self.name.trim().to_string()),
+            description: self
+                .description
+                .as_ref()
+                .map(|desc| desc.trim().to_string()),
+            public: self.public,
+            collaborative: Some(self.collaborative),
+        };
+        match self.playlist {
+            None => details,
+            Some(ref p) => PlaylistDetails {
+                name: details.name.filter(|name| *name != p.name),
+                description: details
+                    .description
+                    .filter(|desc| Some(desc) != p.description.as_ref()),
+                public: details.public.filter(|public| Some(*public) != p.public),
+                collaborative: details.collaborative.filter(|c| *c != p.collaborative),
+            },
+        }
+    }
+
+    /// gets the (mutable) text input of the focused field, if the field is a text field
+    pub fn focused_text_input_mut(&mut self) -> Option<&mut String> {
+        match self.focus {
+            PlaylistEditorField::Name => Some(&mut self.name),
+            PlaylistEditorField::Description => {
+                Some(self.description.get_or_insert_with(String::new))
+            }
+            PlaylistEditorField::Public | PlaylistEditorField::Collaborative => None,
+        }
+    }
+
+    /// toggles the focused field, if the field is a boolean field.
+    /// Because Spotify only allows a collaborative playlist to be private,
+    /// the public and collaborative fields cannot be both enabled.
+    pub fn toggle_focused_field(&mut self) {
+        match self.focus {
+            PlaylistEditorField::Public => {
+                let public = !self.public.unwrap_or_default();
+                self.public = Some(public);
+                if public {
+                    self.collaborative = false;
+                }
+            }
+            PlaylistEditorField::Collaborative => {
+                self.collaborative = !self.collaborative;
+                if self.collaborative {
+                    self.public = Some(false);
+                }
+            }
+            PlaylistEditorField::Name | PlaylistEditorField::Description => {}
+        }
+    }
+}
+
+impl Focusable for PlaylistEditorField {
+    fn next(&mut self) {
+        *self = match self {
+            Self::Name => Self::Description,
+            Self::Description => Self::Public,
+            Self::Public => Self::Collaborative,
+            Self::Collaborative => Self::Name,
+        };
+    }
+
+    fn previous(&mut self) {
+        *self = match self {
+            Self::Name => Self::Collaborative,
+            Self::Description => Self::Name,
+            Self::Public => Self::Description,
+            Self::Collaborative => Self::Public,
+        };
+    }
+}
+
+impl RecommendationsBuilderState {
+    /// adds a seed to the builder, returns `false` if the builder already has
+    /// the maximum number of seeds. Adding an existing seed is a no-op.
+    pub fn add_seed(&mut self, seed: SeedItem) -> bool {
+        if self.seeds.iter().any(|s| s.uri() == seed.uri()) {
+            return true;
+        }
+        if self.seeds.len() + self.genre_seeds().len() >= MAX_RECOMMENDATION_SEEDS {
+            return false;
+        }
+        self.seeds.push(seed);
+        true
+    }
+
+    /// gets the genre seeds from the genres input
+    pub fn genre_seeds(&self) -> Vec<String> {
+        self.genres
+            .split(',')
+            .map(|g| g.trim())
+            .filter(|g| !g.is_empty())
+            .map(|g| g.to_string())
+            .collect()
+    }
+
+    /// gets the (immutable) input of a tuned attribute
+    pub fn attribute_input(&self, attribute: TunableAttribute) -> &str {
+        self.attributes
+            .iter()
+            .find(|(a, _)| *a == attribute)
+            .map(|(_, input)| input.as_str())
+            .unwrap_or_default()
+    }
+
+    /// gets the (mutable) text input of the focused field, if the field is a text field
+    pub fn focused_text_input_mut(&mut self) -> Option<&mut String> {
+        match self.focus {
+            RecommendationsBuilderField::Seeds => None,
+            RecommendationsBuilderField::Genres => Some(&mut self.genres),
+            RecommendationsBuilderField::Attribute(attribute) => {
+                let id = match self.attributes.iter().position(|(a, _)| *a == attribute) {
+                    Some(id) => id,
+                    None => {
+                        self.attributes.push((attribute, String::new()));
+                        self.attributes.len() - 1
+                    }
+                };
+                Some(&mut self.attributes[id].1)
+            }
+        }
+    }
+
+    /// builds a recommendation query from the builder's inputs.
+    /// If an input is invalid, returns the field of the invalid input instead.
+    pub fn query(&self) -> Result<RecommendationQuery, RecommendationsBuilderField> {
+        let mut seeds = self.seeds.clone();
+        seeds.extend(self.genre_seeds().into_iter().map(SeedItem::Genre));
+        if seeds.is_empty() {
+            return Err(RecommendationsBuilderField::Seeds);
+        }
+        if seeds.len() > MAX_RECOMMENDATION_SEEDS {
+            return Err(RecommendationsBuilderField::Genres);
+        }
+
+        let mut attributes = vec![];
+        for (attribute, input) in &self.attributes {
+            for value in input.split_whitespace() {
+                let (bound, value) = match value.chars().next() {
+                    Some('>') => (AttributeBound::Min, &value[1..]),
+                    Some('<') => (AttributeBound::Max, &value[1..]),
+                    _ => (AttributeBound::Target, value),
+                };
+                let value = value
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|value| attribute.is_valid_value(*value))
+                    .ok_or(RecommendationsBuilderField::Attribute(*attribute))?;
+                attributes.push(TunedAttribute {
+                    attribute: *attribute,
+                    bound,
+                    value,
+                });
+            }
+        }
+
+        Ok(RecommendationQuery { seeds, attributes })
+    }
+}
+
+impl Focusable for RecommendationsBuilderField {
+    fn next(&mut self) {
+        let attributes = TunableAttribute::ALL;
+        *self = match self {
+            Self::Seeds => Self::Genres,
+            Self::Genres => Self::Attribute(attributes[0]),
+            Self::Attribute(attribute) => match attributes.iter().position(|a| a == attribute) {
+                Some(id) if id + 1 < attributes.len() => Self::Attribute(attributes[id + 1]),
+                _ => Self::Seeds,
+            },
+        };
+    }
+
+    fn previous(&mut self) {
+        let attributes = TunableAttribute::ALL;
+        *self = match self {
+            Self::Seeds => Self::Attribute(attributes[attributes.len() - 1]),
+            Self::Genres => Self::Seeds,
+            Self::Attribute(attribute) => match attributes.iter().position(|a| a == attribute) {
+                Some(id) if id > 0 => Self::Attribute(attributes[id - 1]),
+                _ => Self::Genres,
+            },
+        };
+    }
+}
+
 impl ActionListItem {
     pub fn n_actions(&self) -> usize {
         match self {
             ActionListItem::Track(.., actions) => actions.len(),
+            ActionListItem::Tracks(.., actions) => actions.len(),
             ActionListItem::Artist(.., actions) => actions.len(),
             ActionListItem::Album(.., actions) => actions.len(),
             ActionListItem::Playlist(.., actions) => actions.len(),
         }
     }
 
-    pub fn name(&self) -> &str {
+    pub fn name(&self) -> String {
         match self {
-            ActionListItem::Track(track, ..) => &track.name,
-            ActionListItem::Artist(artist, ..) => &artist.name,
-            ActionListItem::Album(album, ..) => &album.name,
-            ActionListItem::Playlist(playlist, ..) => &playlist.name,
+            ActionListItem::Track(track, ..) => track.name.clone(),
+            ActionListItem::Tracks(tracks, ..) => format!("{} marked tracks", tracks.len()),
+            ActionListItem::Artist(artist, ..) => artist.name.clone(),
+            ActionListItem::Album(album, ..) => album.name.clone(),
+            ActionListItem::Playlist(playlist, ..) => playlist.name.clone(),
         }
     }
 
     pub fn actions_desc(&self) -> Vec<String> {
         match self {
-            ActionListItem::Track(.., actions) => {
+            ActionListItem::Track(.., actions) | ActionListItem::Tracks(.., actions) => {
                 actions.iter().map(|a| format!("{a:?}")).collect::<Vec<_>>()
             }
             ActionListItem::Artist(.., actions) => {
@@ -111,3 +424,97 @@ impl ActionListItem {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{RecommendationsBuilderField, RecommendationsBuilderState};
+    use crate::state::model::{AttributeBound, SeedItem, TunableAttribute, TunedAttribute};
+
+    /// creates a builder with a genre seed and a tuned attribute's input
+    fn builder(attribute: TunableAttribute, input: &str) -> RecommendationsBuilderState {
+        RecommendationsBuilderState {
+            genres: "rock".to_string(),
+            attributes: vec![(attribute, input.to_string())],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn query_attribute_bounds() {
+        let query = builder(TunableAttribute::Energy, ">0.2 <0.8  0.5")
+            .query()
+            .unwrap();
+        let tuned = |bound, value| TunedAttribute {
+            attribute: TunableAttribute::Energy,
+            bound,
+            value,
+        };
+        assert_eq!(
+            query.attributes,
+            vec![
+                tuned(AttributeBound::Min, 0.2),
+                tuned(AttributeBound::Max, 0.8),
+                tuned(AttributeBound::Target, 0.5),
+            ]
+        );
+        assert_eq!(query.seeds.len(), 1);
+    }
+
+    #[test]
+    fn query_invalid_attribute_values() {
+        for (attribute, input) in [
+            (TunableAttribute::Energy, "high"),
+            (TunableAttribute::Energy, ">"),
+            (TunableAttribute::Danceability, "1.5"),
+            (TunableAttribute::Valence, "<-0.1"),
+            (TunableAttribute::Popularity, "101"),
+            (TunableAttribute::Tempo, "-120"),
+        ] {
+            assert_eq!(
+                builder(attribute, input).query().err(),
+                Some(RecommendationsBuilderField::Attribute(attribute)),
+                "{input:?} should be invalid for {attribute:?}"
+            );
+        }
+        assert!(builder(TunableAttribute::Tempo, ">180").query().is_ok());
+        assert!(builder(TunableAttribute::Popularity, "<100")
+            .query()
+            .is_ok());
+    }
+
+    #[test]
+    fn query_seed_limits() {
+        let mut builder = RecommendationsBuilderState::default();
+        assert_eq!(
+            builder.query().err(),
+            Some(RecommendationsBuilderField::Seeds)
+        );
+
+        builder.genres = "rock, jazz,, pop , blues, funk".to_string();
+        assert_eq!(builder.genre_seeds().len(), 5);
+        assert!(builder.query().is_ok());
+
+        builder.genres.push_str(", soul");
+        assert_eq!(
+            builder.query().err(),
+            Some(RecommendationsBuilderField::Genres)
+        );
+    }
+
+    #[test]
+    fn add_seed_within_limit() {
+        let mut builder = RecommendationsBuilderState {
+            genres: "rock, jazz, pop".to_string(),
+            ..Default::default()
+        };
+        assert!(builder.add_seed(SeedItem::Genre("blues".to_string())));
+        // adding an existing seed is a no-op
+        assert!(builder.add_seed(SeedItem::Genre("blues".to_string())));
+        assert_eq!(builder.seeds.len(), 1);
+
+        assert!(builder.add_seed(SeedItem::Genre("funk".to_string())));
+        // the builder has reached the maximum number of seeds
+        assert!(!builder.add_seed(SeedItem::Genre("soul".to_string())));
+        assert_eq!(builder.seeds.len(), 2);
+    }
+}
@@ -1,22 +1,37 @@
-use crate::{config, event::ClientRequest};
-use anyhow::{Context, Result};
+use crate::{
+    config,
+    event::ClientRequest,
+    state::{SharedState, TrackId},
+};
+use anyhow::{anyhow, Context, Result};
+use librespot_audio::AudioFile;
 use librespot_connect::spirc::Spirc;
 use librespot_core::{
     config::{ConnectConfig, DeviceType},
     session::Session,
+    spotify_id::SpotifyId,
 };
+use librespot_metadata::{FileFormat, Metadata, Track};
 use librespot_playback::mixer::MixerConfig;
 use librespot_playback::{
     audio_backend,
-    config::{AudioFormat, Bitrate, PlayerConfig},
-    mixer::{self, Mixer},
-    player::Player,
+    config::{AudioFormat, Bitrate, NormalisationType, PlayerConfig, VolumeCtrl},
+    mixer,
+    player::{duration_to_coefficient, Player, PlayerEvent},
 };
+use rspotify::prelude::Id;
+use std::time::Duration;
+
+/// the maximum duration to wait for a pre-fetched audio file to be downloaded
+const PREFETCH_TIMEOUT: Duration = Duration::from_secs(120);
+/// the interval to check whether a pre-fetched audio file has been downloaded
+const PREFETCH_CHECK_INTERVAL: Duration = Duration::from_secs(1);
 
 /// Create a new streaming connection
 pub fn new_connection(
     session: Session,
     device: config::DeviceConfig,
+    state: &SharedState,
     client_pub: flume::Sender<ClientRequest>,
     streaming_sub: flume::Receiver<()>,
 ) -> Result<()> {
@@ -24,32 +39,61 @@ pub fn new_connection(
     // while a percentage volume value (from 0 to 100) is used for the device configuration.
     // So we need to convert from one format to another
     let volume = (std::cmp::min(device.volume, 100_u8) as f64 / 100.0 * 65535_f64).round() as u16;
+    let volume_ctrl = parse_option::<VolumeCtrl>(
+        "volume_ctrl",
+        &device.volume_ctrl,
+        &["log", "cubic", "linear", "fixed"],
+    )?;
 
     let connect_config = ConnectConfig {
         name: device.name,
         device_type: device.device_type.parse::<DeviceType>().unwrap_or_default(),
         initial_volume: Some(volume),
-
-        // non-configurable fields, use default values.
-        // We may allow users to configure these fields in a future release
-        has_volume_ctrl: true,
+        // the device's volume cannot be changed with a fixed volume control
+        has_volume_ctrl: !matches!(volume_ctrl, VolumeCtrl::Fixed),
+        // autoplay is handled by the application for every device (see the `autoplay` config option),
+        // so librespot's autoplay is disabled to not interfere with it
         autoplay: false,
     };
 
     tracing::info!("Application's connect configurations: {:?}", connect_config);
 
-    let mixer =
-        Box::new(mixer::softmixer::SoftMixer::open(MixerConfig::default())) as Box<dyn Mixer>;
+    let mixer_config = MixerConfig {
+        device: device.mixer_device,
+        control: device.mixer_control,
+        volume_ctrl,
+        ..Default::default()
+    };
+    let mixer_fn = mixer::find(Some(&device.mixer))
+        .with_context(|| format!("unable to find a mixer {}", device.mixer))?;
+    let mixer = mixer_fn(mixer_config);
     mixer.set_volume(volume);
 
-    let backend =
-        audio_backend::find(None).with_context(|| "unable to find an audio backend".to_string())?;
+    let backend = audio_backend::find(device.audio_backend.clone()).with_context(|| {
+        format!(
+            "unable to find an audio backend {}",
+            device.audio_backend.as_deref().unwrap_or_default()
+        )
+    })?;
+    let audio_device = device.audio_device.clone();
+    let audio_format = parse_audio_format(&device.audio_format)?;
     let player_config = PlayerConfig {
-        bitrate: device
-            .bitrate
-            .to_string()
-            .parse::<Bitrate>()
-            .unwrap_or_default(),
+        bitrate: parse_bitrate(device.bitrate),
+        gapless: device.gapless,
+        normalisation: device.normalisation,
+        normalisation_type: parse_option::<NormalisationType>(
+            "normalisation_type",
+            &device.normalisation_type,
+            &["album", "track", "auto"],
+        )?,
+        normalisation_pregain_db: device.normalisation_pregain,
+        normalisation_threshold_dbfs: device.normalisation_threshold,
+        normalisation_attack_cf: duration_to_coefficient(Duration::from_millis(
+            device.normalisation_attack,
+        )),
+        normalisation_release_cf: duration_to_coefficient(Duration::from_millis(
+            device.normalisation_release,
+        )),
         ..Default::default()
     };
 
@@ -62,28 +106,40 @@ pub fn new_connection(
         player_config,
         session.clone(),
         mixer.get_soft_volume(),
-        move || backend(None, AudioFormat::default()),
+        move || backend(audio_device, audio_format),
     );
 
+    let device_id = session.device_id().to_string();
+    state.player.write().integrated_device_id = Some(device_id.clone());
+
     tokio::task::spawn({
+        let state = state.clone();
         async move {
             while let Some(event) = channel.recv().await {
                 tracing::info!("Got an event from the integrated player: {:?}", event);
-                client_pub
-                    .send_async(ClientRequest::GetCurrentPlayback)
-                    .await
-                    .unwrap_or_default();
+                if !handle_player_event(&state, &device_id, &event) {
+                    client_pub
+                        .send_async(ClientRequest::GetCurrentPlayback)
+                        .await
+                        .unwrap_or_default();
+                }
             }
         }
     });
 
     tracing::info!("Starting an integrated Spotify player using librespot's spirc protocol");
 
-    let (spirc, spirc_task) = Spirc::new(connect_config, session, player, mixer);
+    let (spirc, spirc_task) = Spirc::new(connect_config, session.clone(), player, mixer);
     tokio::task::spawn({
         async move {
             tokio::select! {
-                _ = spirc_task => {}
+                _ = spirc_task => {
+                    // The spirc task stops when the session is terminated or the connection fails.
+                    // Shutting down the session notifies the session supervisor to reconnect it
+                    // along with a new streaming connection.
+                    tracing::warn!("The integrated player's connection was terminated");
+                    session.shutdown();
+                }
                 _ = streaming_sub.recv_async() => {
                     tracing::info!("Got a shutdown request, shutdown the current streaming connection...");
                     spirc.shutdown();
@@ -94,3 +150,170 @@ pub fn new_connection(
 
     Ok(())
 }
+
+/// parses a device configuration option's value,
+/// returns an error listing the option's accepted values if the value is invalid
+fn parse_option<T: std::str::FromStr>(name: &str, value: &str, accepted: &[&str]) -> Result<T> {
+    value.parse::<T>().map_err(|_| {
+        anyhow!(
+            "invalid `{name}` device config option {value:?}, accepted values: {}",
+            accepted.join(", ")
+        )
+    })
+}
+
+fn parse_audio_format(format: &str) -> Result<AudioFormat> {
+    parse_option(
+        "audio_format",
+        format,
+        &["F64", "F32", "S32", "S24", "S24_3", "S16"],
+    )
+}
+
+fn parse_bitrate(bitrate: u16) -> Bitrate {
+    bitrate.to_string().parse::<Bitrate>().unwrap_or_default()
+}
+
+/// downloads a track's audio file into the session's audio cache,
+/// so that the track can be played without downloading the file again.
+/// The audio file's format is chosen in the same way as the integrated player.
+pub async fn prefetch_track(session: &Session, id: &TrackId, bitrate: u16) -> Result<()> {
+    let spotify_id =
+        SpotifyId::from_base62(id.id()).map_err(|_| anyhow!("invalid track id {id}"))?;
+    let track = Track::get(session, spotify_id)
+        .await
+        .map_err(|_| anyhow!("failed to get the metadata of track {id}"))?;
+    if !track.available {
+        anyhow::bail!("track {id} is unavailable");
+    }
+
+    let (formats, bytes_per_second) = match parse_bitrate(bitrate) {
+        Bitrate::Bitrate96 => (
+            [
+                FileFormat::OGG_VORBIS_96,
+                FileFormat::OGG_VORBIS_160,
+                FileFormat::OGG_VORBIS_320,
+            ],
+            12 * 1024,
+        ),
+        Bitrate::Bitrate160 => (
+            [
+                FileFormat::OGG_VORBIS_160,
+                FileFormat::OGG_VORBIS_96,
+                FileFormat::OGG_VORBIS_320,
+            ],
+            20 * 1024,
+        ),
+        Bitrate::Bitrate320 => (
+            [
+                FileFormat::OGG_VORBIS_320,
+                FileFormat::OGG_VORBIS_160,
+                FileFormat::OGG_VORBIS_96,
+            ],
+            40 * 1024,
+        ),
+    };
+    let file_id = formats
+        .iter()
+        .find_map(|format| track.files.get(format))
+        .with_context(|| format!("no supported audio file found for track {id}"))?;
+
+    let file = AudioFile::open(session, *file_id, bytes_per_second, false)
+        .await
+        .map_err(|_| anyhow!("failed to open the audio file of track {id}"))?;
+    if file.is_cached() {
+        return Ok(());
+    }
+
+    // Download the whole file while keeping the audio file open until the download completes.
+    // The downloaded file is then stored into the cache by librespot.
+    let controller = file.get_stream_loader_controller();
+    controller.set_stream_mode();
+    controller.fetch_next(controller.len());
+    let deadline = tokio::time::Instant::now() + PREFETCH_TIMEOUT;
+    while !controller.range_to_end_available() {
+        if tokio::time::Instant::now() > deadline {
+            // stop downloading the stalled file
+            controller.close();
+            anyhow::bail!("timed out downloading the audio file of track {id}");
+        }
+        tokio::time::sleep(PREFETCH_CHECK_INTERVAL).await;
+    }
+    tracing::info!("Pre-fetched the audio file of track {id}");
+
+    Ok(())
+}
+
+/// updates the integrated device's playback based on an event of the integrated player.
+/// Returns `false` if the event cannot be applied to the playback, which then needs to be
+/// retrieved from Spotify, e.g. when the player moves to a new track.
+fn handle_player_event(state: &SharedState, device_id: &str, event: &PlayerEvent) -> bool {
+    let mut player = state.player.write();
+    let duration = player.current_playing_item_duration();
+    let playback = match player.playback {
+        Some(ref mut playback) if playback.device.id.as_deref() == Some(device_id) => playback,
+        _ => return false,
+    };
+
+    match *event {
+        // a `Playing` or `Paused` event is also fired after seeking the track
+        PlayerEvent::Playing { position_ms, .. } => {
+            playback.is_playing = true;
+            playback.progress = Some(Duration::from_millis(position_ms.into()));
+        }
+        PlayerEvent::Paused { position_ms, .. } => {
+            playback.is_playing = false;
+            playback.progress = Some(Duration::from_millis(position_ms.into()));
+        }
+        PlayerEvent::VolumeSet { volume } => {
+            let percent = (volume as f64 / 65535_f64 * 100.0).round() as u32;
+            playback.device.volume_percent = Some(percent);
+        }
+        // the player moves to the next track (`Changed` event)
+        // or stops at the end of the playing context (`Stopped` event) after the current track ends
+        PlayerEvent::EndOfTrack { .. } => {
+            playback.progress = duration;
+        }
+        PlayerEvent::Started { .. }
+        | PlayerEvent::Changed { .. }
+        | PlayerEvent::Stopped { .. }
+        | PlayerEvent::Unavailable { .. } => return false,
+        // events of loading tracks don't change the playback
+        PlayerEvent::Loading { .. }
+        | PlayerEvent::Preloading { .. }
+        | PlayerEvent::TimeToPreloadNextTrack { .. } => return true,
+    }
+    player.playback_last_updated_time = Some(std::time::Instant::now());
+    true
+}
+
+/// audio backends whose output devices are listed by opening the backend with the `?` device
+const DEVICE_LISTING_BACKENDS: &[&str] = &["rodio", "rodiojack", "alsa", "portaudio"];
+
+/// prints the available audio backends and the output devices of the configured audio backend
+pub fn list_audio_devices(device: &config::DeviceConfig) -> Result<()> {
+    let audio_format = parse_audio_format(&device.audio_format)?;
+    let name = match device.audio_backend {
+        Some(ref name) => name.as_str(),
+        None => audio_backend::BACKENDS
+            .first()
+            .map(|backend| backend.0)
+            .context("no audio backend is available")?,
+    };
+
+    println!("Available Audio Backends:");
+    for backend in audio_backend::BACKENDS {
+        let suffix = if backend.0 == name { " (selected)" } else { "" };
+        println!("  {}{suffix}", backend.0);
+    }
+
+    if !DEVICE_LISTING_BACKENDS.contains(&name) {
+        println!("Listing output devices is not supported by the {name} audio backend");
+        return Ok(());
+    }
+    let backend = audio_backend::find(Some(name.to_string()))
+        .with_context(|| format!("unable to find an audio backend {name}"))?;
+    // the backend prints its output devices, then exits the process
+    backend(Some("?".to_string()), audio_format);
+    Ok(())
+}
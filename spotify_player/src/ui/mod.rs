@@ -11,7 +11,7 @@ mod popup;
 mod utils;
 
 /// run the application UI
-pub fn run(state: SharedState) -> Result<()> {
+pub fn run(state: SharedState, cache_folder: &std::path::Path) -> Result<()> {
     let mut terminal = init_ui().context("failed to initialize the application's UI")?;
 
     let ui_refresh_duration =
@@ -21,6 +21,10 @@ pub fn run(state: SharedState) -> Result<()> {
             let mut ui = state.ui.lock();
             if !ui.is_running {
                 clean_up(terminal).context("failed to clean up the application's UI resources")?;
+                // persist the data updated since the data persister's last write
+                if let Err(err) = state.data.read().persist_data(cache_folder) {
+                    tracing::warn!("Failed to persist the application's data: {err:#}");
+                }
                 std::process::exit(0);
             }
 
@@ -108,6 +112,7 @@ fn render_main_layout(
         PageType::Search => page::render_search_page(is_active, frame, state, ui, chunks[1]),
         PageType::Context => page::render_context_page(is_active, frame, state, ui, chunks[1]),
         PageType::Tracks => page::render_tracks_page(is_active, frame, state, ui, chunks[1]),
+        PageType::Queue => page::render_queue_page(is_active, frame, state, ui, chunks[1]),
         PageType::Browse => page::render_browse_page(is_active, frame, state, ui, chunks[1]),
         #[cfg(feature = "lyric-finder")]
         PageType::Lyric => page::render_lyric_page(is_active, frame, state, ui, chunks[1]),
@@ -9,35 +9,59 @@ pub fn render_search_page(
 ) -> Result<()> {
     let data = state.data.read();
 
-    let (focus_state, current_query, input) = match ui.current_page() {
+    let (focus_state, search_type, current_query, input) = match ui.current_page() {
         PageState::Search {
             state,
             current_query,
             input,
-        } => (state.focus, current_query, input),
+        } => (state.focus, state.search_type, current_query, input),
         s => anyhow::bail!("expect a search page state, found {s:?}"),
     };
 
     let search_results = data.caches.search.peek(current_query);
 
+    // a window's title shows the number of loaded results and the total number of results
+    let window_title = |name: &str, t: SearchType, is_active: bool| {
+        let count = match search_results.and_then(|s| Some((s, s.totals.get(&t)?))) {
+            Some((s, total)) => format!(" ({}/{total})", s.len(t)),
+            None => String::new(),
+        };
+        format!("{name}{count}{}", if is_active { " [*]" } else { "" })
+    };
+    // only the window of the search type is shown if the page's search type is specified
+    let borders = |borders: Borders| {
+        if search_type.is_some() {
+            Borders::TOP
+        } else {
+            borders
+        }
+    };
+
     let (track_list, n_tracks) = {
         let track_items = search_results
             .map(|s| {
                 s.tracks
                     .iter()
-                    .map(|a| (format!("{} • {}", a.name, a.artists_info()), false))
+                    .map(|a| {
+                        let style = if ui.is_marked_track(a) {
+                            ui.theme.marked_track()
+                        } else {
+                            Style::default()
+                        };
+                        (format!("{} • {}", a.name, a.artists_info()), style)
+                    })
                     .collect::<Vec<_>>()
             })
             .unwrap_or_default();
 
         let is_active = is_active && focus_state == SearchFocusState::Tracks;
 
-        utils::construct_list_widget(
+        utils::construct_styled_list_widget(
             &ui.theme,
             track_items,
-            &format!("Tracks{}", if is_active { " [*]" } else { "" }),
+            &window_title("Tracks", SearchType::Track, is_active),
             is_active,
-            Some(Borders::TOP | Borders::RIGHT),
+            Some(borders(Borders::TOP | Borders::RIGHT)),
         )
     };
 
@@ -56,9 +80,9 @@ pub fn render_search_page(
         utils::construct_list_widget(
             &ui.theme,
             album_items,
-            &format!("Albums{}", if is_active { " [*]" } else { "" }),
+            &window_title("Albums", SearchType::Album, is_active),
             is_active,
-            Some(Borders::TOP),
+            Some(borders(Borders::TOP)),
         )
     };
 
@@ -77,9 +101,9 @@ pub fn render_search_page(
         utils::construct_list_widget(
             &ui.theme,
             artist_items,
-            &format!("Artists{}", if is_active { " [*]" } else { "" }),
+            &window_title("Artists", SearchType::Artist, is_active),
             is_active,
-            Some(Borders::TOP | Borders::RIGHT),
+            Some(borders(Borders::TOP | Borders::RIGHT)),
         )
     };
 
@@ -98,15 +122,19 @@ pub fn render_search_page(
         utils::construct_list_widget(
             &ui.theme,
             playlist_items,
-            &format!("Playlists{}", if is_active { " [*]" } else { "" }),
+            &window_title("Playlists", SearchType::Playlist, is_active),
             is_active,
-            Some(Borders::TOP),
+            Some(borders(Borders::TOP)),
         )
     };
 
     // renders borders with title
+    let title = match search_type {
+        Some(t) => format!("Search ({t:?}s)"),
+        None => "Search".to_string(),
+    };
     let block = Block::default()
-        .title(ui.theme.block_title_with_style("Search"))
+        .title(ui.theme.block_title_with_style(title))
         .borders(Borders::ALL);
     frame.render_widget(block, rect);
 
@@ -128,54 +156,89 @@ pub fn render_search_page(
         chunks[1]
     };
 
-    // split the given `rect` layout into a 2x2 layout consiting of 4 chunks
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
-        .split(rect)
-        .into_iter()
-        .flat_map(|rect| {
-            Layout::default()
-                .direction(Direction::Horizontal)
-                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
-                .split(rect)
-        })
-        .collect::<Vec<_>>();
-
     // Render the search page's windows.
     // Will need mutable access to the list/table states stored inside the page state for rendering.
     let page_state = match ui.current_page_mut() {
         PageState::Search { state, .. } => state,
         s => anyhow::bail!("expect a search page state, found {s:?}"),
     };
-    utils::render_list_window(
-        frame,
-        track_list,
-        chunks[0],
-        n_tracks,
-        &mut page_state.track_list,
-    );
-    utils::render_list_window(
-        frame,
-        album_list,
-        chunks[1],
-        n_albums,
-        &mut page_state.album_list,
-    );
-    utils::render_list_window(
-        frame,
-        artist_list,
-        chunks[2],
-        n_artists,
-        &mut page_state.artist_list,
-    );
-    utils::render_list_window(
-        frame,
-        playlist_list,
-        chunks[3],
-        n_playlists,
-        &mut page_state.playlist_list,
-    );
+
+    match search_type {
+        Some(SearchType::Track) => utils::render_list_window(
+            frame,
+            track_list,
+            rect,
+            n_tracks,
+            &mut page_state.track_list,
+        ),
+        Some(SearchType::Album) => utils::render_list_window(
+            frame,
+            album_list,
+            rect,
+            n_albums,
+            &mut page_state.album_list,
+        ),
+        Some(SearchType::Artist) => utils::render_list_window(
+            frame,
+            artist_list,
+            rect,
+            n_artists,
+            &mut page_state.artist_list,
+        ),
+        Some(SearchType::Playlist) => utils::render_list_window(
+            frame,
+            playlist_list,
+            rect,
+            n_playlists,
+            &mut page_state.playlist_list,
+        ),
+        None => {
+            // split the given `rect` layout into a 2x2 layout consiting of 4 chunks
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+                .split(rect)
+                .into_iter()
+                .flat_map(|rect| {
+                    Layout::default()
+                        .direction(Direction::Horizontal)
+                        .constraints(
+                            [Constraint::Percentage(50), Constraint::Percentage(50)].as_ref(),
+                        )
+                        .split(rect)
+                })
+                .collect::<Vec<_>>();
+
+            utils::render_list_window(
+                frame,
+                track_list,
+                chunks[0],
+                n_tracks,
+                &mut page_state.track_list,
+            );
+            utils::render_list_window(
+                frame,
+                album_list,
+                chunks[1],
+                n_albums,
+                &mut page_state.album_list,
+            );
+            utils::render_list_window(
+                frame,
+                artist_list,
+                chunks[2],
+                n_artists,
+                &mut page_state.artist_list,
+            );
+            utils::render_list_window(
+                frame,
+                playlist_list,
+                chunks[3],
+                n_playlists,
+                &mut page_state.playlist_list,
+            );
+        }
+    }
 
     Ok(())
 }
@@ -214,7 +277,8 @@ pub fn render_context_page(
         Some(id) => id.uri(),
     };
 
-    match state.data.read().caches.context.peek(&context_uri) {
+    let data = state.data.read();
+    match data.caches.context.peek(&context_uri) {
         Some(context) => {
             frame.render_widget(block, rect);
 
@@ -224,14 +288,21 @@ pub fn render_context_page(
                 .margin(1)
                 .constraints([Constraint::Length(1), Constraint::Min(0)].as_ref())
                 .split(rect);
-            let page_desc = Paragraph::new(context.description())
-                .block(Block::default().style(ui.theme.page_desc()));
+            let mut desc = context.description();
+            if data.caches.incomplete_contexts.contains(&context_uri) {
+                desc += " (loading...)";
+            }
+            let page_desc =
+                Paragraph::new(desc).block(Block::default().style(ui.theme.page_desc()));
             frame.render_widget(page_desc, chunks[0]);
 
             match context {
                 Context::Artist {
                     top_tracks,
                     albums,
+                    singles,
+                    compilations,
+                    appears_on,
                     related_artists,
                     ..
                 } => {
@@ -241,7 +312,11 @@ pub fn render_context_page(
                         state,
                         ui,
                         chunks[1],
-                        (top_tracks, albums, related_artists),
+                        (
+                            top_tracks,
+                            [albums, singles, compilations, appears_on],
+                            related_artists,
+                        ),
                     )?;
                 }
                 Context::Playlist { tracks, .. } => {
@@ -264,6 +339,16 @@ pub fn render_context_page(
                         ui,
                     )?;
                 }
+                Context::Show { episodes, .. } => {
+                    render_episode_table_window(
+                        frame,
+                        chunks[1],
+                        is_active,
+                        state,
+                        ui.search_filtered_items(episodes),
+                        ui,
+                    )?;
+                }
             }
         }
         None => {
@@ -289,22 +374,25 @@ pub fn render_library_page(
         s => anyhow::bail!("expect a library page state, found {s:?}"),
     };
 
-    // Horizontally split the library page into 3 windows:
+    // Horizontally split the library page into 4 windows:
     // - a playlists window
     // - a saved albums window
     // - a followed artists window
+    // - a saved shows window
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints(
             [
-                Constraint::Percentage(40),
-                Constraint::Percentage(40),
-                Constraint::Percentage(20),
+                Constraint::Percentage(35),
+                Constraint::Percentage(35),
+                Constraint::Percentage(15),
+                Constraint::Percentage(15),
             ]
             .as_ref(),
         )
         .split(rect);
-    let (playlist_rect, album_rect, artist_rect) = (chunks[0], chunks[1], chunks[2]);
+    let (playlist_rect, album_rect, artist_rect, show_rect) =
+        (chunks[0], chunks[1], chunks[2], chunks[3]);
 
     // Construct the playlist window
     let (playlist_list, n_playlists) = utils::construct_list_widget(
@@ -337,6 +425,17 @@ pub fn render_library_page(
             .collect(),
         "Artists",
         is_active && focus_state == LibraryFocusState::FollowedArtists,
+        Some((Borders::TOP | Borders::LEFT) | Borders::BOTTOM),
+    );
+    // Construct the saved show window
+    let (show_list, n_shows) = utils::construct_list_widget(
+        &ui.theme,
+        ui.search_filtered_items(&data.user_data.saved_shows)
+            .into_iter()
+            .map(|s| (s.to_string(), curr_context_uri == Some(s.id.uri())))
+            .collect(),
+        "Shows",
+        is_active && focus_state == LibraryFocusState::SavedShows,
         None,
     );
 
@@ -368,6 +467,13 @@ pub fn render_library_page(
         n_artists,
         &mut page_state.followed_artist_list,
     );
+    utils::render_list_window(
+        frame,
+        show_list,
+        show_rect,
+        n_shows,
+        &mut page_state.saved_show_list,
+    );
 
     Ok(())
 }
@@ -424,6 +530,51 @@ pub fn render_tracks_page(
     )
 }
 
+pub fn render_queue_page(
+    is_active: bool,
+    frame: &mut Frame,
+    state: &SharedState,
+    ui: &mut UIStateGuard,
+    rect: Rect,
+) -> Result<()> {
+    // the queue is cloned to not hold the player's lock,
+    // which is also acquired when rendering the track table
+    let queue = state.player.read().queue.clone();
+
+    let block = Block::default()
+        .title(ui.theme.block_title_with_style("Queue"))
+        .borders(Borders::ALL);
+
+    let queue = match queue {
+        Some(queue) => queue,
+        None => {
+            // the queue is still loading
+            frame.render_widget(Paragraph::new("loading...").block(block), rect);
+            return Ok(());
+        }
+    };
+
+    // render the window's border and title
+    frame.render_widget(block, rect);
+
+    // render the window's description
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([Constraint::Length(1), Constraint::Min(0)].as_ref())
+        .split(rect);
+    // episodes in the queue are not displayed in the track table
+    let n_episodes = queue.num_upcoming_episodes();
+    let mut desc = format!("{} upcoming tracks", queue.queue.len() - n_episodes);
+    if n_episodes > 0 {
+        desc += &format!(" and {n_episodes} episodes (not shown)");
+    }
+    let page_desc = Paragraph::new(desc).block(Block::default().style(ui.theme.page_desc()));
+    frame.render_widget(page_desc, chunks[0]);
+
+    render_track_table_window(frame, chunks[1], is_active, state, queue.tracks(), ui)
+}
+
 pub fn render_browse_page(
     is_active: bool,
     frame: &mut Frame,
@@ -465,6 +616,31 @@ pub fn render_browse_page(
                     None,
                 )
             }
+            BrowsePageUIState::UserPlaylists { user_id, .. } => {
+                let playlists = match data.browse.user_playlists.get(user_id.id()) {
+                    Some(playlists) => playlists,
+                    None => {
+                        utils::render_loading_window(&ui.theme, frame, rect, "User Playlists");
+                        return Ok(());
+                    }
+                };
+                // a user's display name is only available via the user's own playlists
+                let owner = playlists
+                    .iter()
+                    .find(|p| p.owner.1 == *user_id)
+                    .map(|p| p.owner.0.as_str())
+                    .unwrap_or(user_id.id());
+                utils::construct_list_widget(
+                    &ui.theme,
+                    ui.search_filtered_items(playlists)
+                        .into_iter()
+                        .map(|c| (c.name.clone(), false))
+                        .collect(),
+                    &format!("{owner}'s Playlists"),
+                    is_active,
+                    None,
+                )
+            }
         },
         s => anyhow::bail!("expect a browse page state, found {s:?}"),
     };
@@ -554,11 +730,11 @@ fn render_artist_context_page_windows(
     state: &SharedState,
     ui: &mut UIStateGuard,
     rect: Rect,
-    data: (&[Track], &[Album], &[Artist]),
+    data: (&[Track], [&[Album]; 4], &[Artist]),
 ) -> Result<()> {
-    let (tracks, albums, artists) = (
+    let (tracks, album_sections, artists) = (
         ui.search_filtered_items(data.0),
-        ui.search_filtered_items(data.1),
+        data.1.map(|albums| ui.search_filtered_items(albums)),
         ui.search_filtered_items(data.2),
     );
 
@@ -592,25 +768,65 @@ fn render_artist_context_page_windows(
 
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+        .constraints([Constraint::Percentage(70), Constraint::Percentage(30)].as_ref())
         .split(rect);
 
-    // construct album list widget
-    let (album_list, n_albums) = {
-        let album_items = albums
-            .into_iter()
-            .map(|a| (a.name.clone(), false))
-            .collect::<Vec<_>>();
-
-        utils::construct_list_widget(
-            &ui.theme,
-            album_items,
-            "Albums",
-            is_active && focus_state == ArtistFocusState::Albums,
-            Some(Borders::TOP),
-        )
+    // split the discography area into a 2x2 grid of album sections
+    let album_rects = {
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+            .split(chunks[0]);
+        rows.iter()
+            .flat_map(|row| {
+                Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+                    .split(*row)
+                    .to_vec()
+            })
+            .collect::<Vec<_>>()
     };
 
+    // construct album list widgets
+    let sections = [
+        ("Albums", ArtistFocusState::Albums, Borders::TOP),
+        (
+            "Singles and EPs",
+            ArtistFocusState::Singles,
+            Borders::TOP | Borders::LEFT,
+        ),
+        ("Compilations", ArtistFocusState::Compilations, Borders::TOP),
+        (
+            "Appears On",
+            ArtistFocusState::AppearsOn,
+            Borders::TOP | Borders::LEFT,
+        ),
+    ];
+    let album_lists = sections
+        .into_iter()
+        .zip(album_sections)
+        .map(|((title, focus, borders), albums)| {
+            let album_items = albums
+                .into_iter()
+                .map(|a| {
+                    (
+                        format!("{} • {} • {}", a.name, a.album_type, a.year()),
+                        false,
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            utils::construct_list_widget(
+                &ui.theme,
+                album_items,
+                title,
+                is_active && focus_state == focus,
+                Some(borders),
+            )
+        })
+        .collect::<Vec<_>>();
+
     // construct artist list widget
     let (artist_list, n_artists) = {
         let artist_items = artists
@@ -627,20 +843,32 @@ fn render_artist_context_page_windows(
         )
     };
 
-    let (album_list_state, artist_list_state) = match ui.current_page_mut() {
+    let (album_list_states, artist_list_state) = match ui.current_page_mut() {
         PageState::Context {
             state:
                 Some(ContextPageUIState::Artist {
                     album_list,
+                    single_list,
+                    compilation_list,
+                    appears_on_list,
                     related_artist_list,
                     ..
                 }),
             ..
-        } => (album_list, related_artist_list),
+        } => (
+            [album_list, single_list, compilation_list, appears_on_list],
+            related_artist_list,
+        ),
         s => anyhow::bail!("expect an artist context page state, found {s:?}"),
     };
 
-    utils::render_list_window(frame, album_list, chunks[0], n_albums, album_list_state);
+    for (((album_list, n_albums), list_state), rect) in album_lists
+        .into_iter()
+        .zip(album_list_states)
+        .zip(album_rects)
+    {
+        utils::render_list_window(frame, album_list, rect, n_albums, list_state);
+    }
     utils::render_list_window(frame, artist_list, chunks[1], n_artists, artist_list_state);
 
     Ok(())
@@ -669,18 +897,29 @@ pub fn render_track_table_window(
         }
     }
 
+    // audio features columns are only shown if there is a track with audio features
+    let show_audio_features = tracks.iter().any(|t| t.audio_features.is_some());
+
     let item_max_len = state.app_config.track_table_item_max_len;
     let n_tracks = tracks.len();
     let rows = tracks
         .into_iter()
         .enumerate()
         .map(|(id, t)| {
+            let style = if ui.is_marked_track(t) {
+                ui.theme.marked_track()
+            } else {
+                Style::default()
+            };
             let (id, style) = if playing_track_uri == t.id.uri() {
-                (active_desc.to_string(), ui.theme.current_playing())
+                (
+                    active_desc.to_string(),
+                    style.patch(ui.theme.current_playing()),
+                )
             } else {
-                ((id + 1).to_string(), Style::default())
+                ((id + 1).to_string(), style)
             };
-            Row::new(vec![
+            let mut cells = vec![
                 Cell::from(id),
                 Cell::from(crate::utils::truncate_string(t.name.clone(), item_max_len)),
                 Cell::from(crate::utils::truncate_string(
@@ -689,30 +928,55 @@ pub fn render_track_table_window(
                 )),
                 Cell::from(crate::utils::truncate_string(t.album_info(), item_max_len)),
                 Cell::from(crate::utils::format_duration(t.duration)),
-            ])
-            .style(style)
+            ];
+            if show_audio_features {
+                let features = match t.audio_features {
+                    Some(ref f) => vec![
+                        format!("{:.0}", f.tempo),
+                        f.key_info(),
+                        format!("{:.0}", f.energy * 100.0),
+                        format!("{:.0}", f.danceability * 100.0),
+                        format!("{:.0}", f.valence * 100.0),
+                    ],
+                    None => vec![String::new(); 5],
+                };
+                cells.extend(features.into_iter().map(Cell::from));
+            }
+            Row::new(cells).style(style)
         })
         .collect::<Vec<_>>();
 
+    let mut header = vec!["#", "Track", "Artists", "Album", "Duration"];
+    let mut widths = vec![
+        Constraint::Length(5),
+        Constraint::Percentage(25),
+        Constraint::Percentage(25),
+        Constraint::Percentage(30),
+        Constraint::Percentage(15),
+    ];
+    if show_audio_features {
+        header.extend(["BPM", "Key", "Energy", "Dance", "Valence"]);
+        widths = vec![
+            Constraint::Length(5),
+            Constraint::Percentage(20),
+            Constraint::Percentage(18),
+            Constraint::Percentage(18),
+            Constraint::Length(8),
+            Constraint::Length(5),
+            Constraint::Length(4),
+            Constraint::Length(6),
+            Constraint::Length(5),
+            Constraint::Length(7),
+        ];
+    }
+
     let track_table = Table::new(rows)
         .header(
-            Row::new(vec![
-                Cell::from("#"),
-                Cell::from("Track"),
-                Cell::from("Artists"),
-                Cell::from("Album"),
-                Cell::from("Duration"),
-            ])
-            .style(ui.theme.table_header()),
+            Row::new(header.into_iter().map(Cell::from).collect::<Vec<_>>())
+                .style(ui.theme.table_header()),
         )
         .block(Block::default())
-        .widths(&[
-            Constraint::Length(5),
-            Constraint::Percentage(25),
-            Constraint::Percentage(25),
-            Constraint::Percentage(30),
-            Constraint::Percentage(15),
-        ])
+        .widths(&widths)
         .highlight_style(ui.theme.selection_style(is_active));
 
     match ui.current_page_mut() {
@@ -725,10 +989,13 @@ pub fn render_track_table_window(
                 } => top_track_table,
                 ContextPageUIState::Playlist { track_table } => track_table,
                 ContextPageUIState::Album { track_table } => track_table,
+                ContextPageUIState::Show { .. } => {
+                    anyhow::bail!("a show context page doesn't have a track table")
+                }
             };
             utils::render_table_window(frame, track_table, rect, n_tracks, track_table_state);
         }
-        PageState::Tracks { state, .. } => {
+        PageState::Tracks { state, .. } | PageState::Queue { state } => {
             utils::render_table_window(frame, track_table, rect, n_tracks, state);
         }
         s => anyhow::bail!("reach unsupported page state {s:?} when rendering track table"),
@@ -736,3 +1003,85 @@ pub fn render_track_table_window(
 
     Ok(())
 }
+
+pub fn render_episode_table_window(
+    frame: &mut Frame,
+    rect: Rect,
+    is_active: bool,
+    state: &SharedState,
+    episodes: Vec<&Episode>,
+    ui: &mut UIStateGuard,
+) -> Result<()> {
+    // get the current playing episode's URI to decorate such episode (if exists) in the episode table
+    let mut playing_episode_uri = "".to_string();
+    let mut active_desc = "";
+    if let Some(ref playback) = state.player.read().playback {
+        if let Some(rspotify_model::PlayableItem::Episode(ref episode)) = playback.item {
+            playing_episode_uri = episode.id.uri();
+
+            active_desc = if !playback.is_playing {
+                &state.app_config.pause_icon
+            } else {
+                &state.app_config.play_icon
+            };
+        }
+    }
+
+    let item_max_len = state.app_config.track_table_item_max_len;
+    let n_episodes = episodes.len();
+    let rows = episodes
+        .into_iter()
+        .enumerate()
+        .map(|(id, e)| {
+            let (id, style) = if playing_episode_uri == e.id.uri() {
+                (active_desc.to_string(), ui.theme.current_playing())
+            } else {
+                ((id + 1).to_string(), Style::default())
+            };
+            Row::new(vec![
+                Cell::from(id),
+                Cell::from(crate::utils::truncate_string(e.name.clone(), item_max_len)),
+                Cell::from(e.release_date.clone()),
+                Cell::from(crate::utils::format_duration(e.duration)),
+                Cell::from(e.progress_info()),
+            ])
+            .style(style)
+        })
+        .collect::<Vec<_>>();
+
+    let episode_table = Table::new(rows)
+        .header(
+            Row::new(vec![
+                Cell::from("#"),
+                Cell::from("Episode"),
+                Cell::from("Release Date"),
+                Cell::from("Duration"),
+                Cell::from("Progress"),
+            ])
+            .style(ui.theme.table_header()),
+        )
+        .block(Block::default())
+        .widths(&[
+            Constraint::Length(5),
+            Constraint::Percentage(55),
+            Constraint::Percentage(15),
+            Constraint::Percentage(10),
+            Constraint::Percentage(15),
+        ])
+        .highlight_style(ui.theme.selection_style(is_active));
+
+    match ui.current_page_mut() {
+        PageState::Context {
+            state:
+                Some(ContextPageUIState::Show {
+                    episode_table: state,
+                }),
+            ..
+        } => {
+            utils::render_table_window(frame, episode_table, rect, n_episodes, state);
+        }
+        s => anyhow::bail!("reach unsupported page state {s:?} when rendering episode table"),
+    }
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use super::*;
 
 /// Renders a playback window showing information about the current playback, which includes
-/// - track title, artists, album
+/// - track title, artists, album (or episode title, publisher, show for a podcast episode)
 /// - playback metadata (playing state, repeat state, shuffle state, volume, device, etc)
 /// - cover image (if `image` feature is enabled)
 /// - playback progress bar
@@ -28,7 +28,12 @@ pub fn render_playback_window(
 
     let player = state.player.read();
     if let Some(ref playback) = player.playback {
-        if let Some(rspotify::model::PlayableItem::Track(ref track)) = playback.item {
+        if let Some(ref item) = playback.item {
+            let duration = match item {
+                rspotify_model::PlayableItem::Track(track) => track.duration,
+                rspotify_model::PlayableItem::Episode(episode) => episode.duration,
+            };
+
             let (metadata_rect, progress_bar_rect) = {
                 // allocate the progress bar rect
                 let (rect, progress_bar_rect) = {
@@ -73,7 +78,7 @@ pub fn render_playback_window(
                             (hor_chunks[2], ver_chunks[0])
                         };
 
-                        let url = crate::utils::get_track_album_image_url(track).map(String::from);
+                        let url = player.current_playing_item_cover_url().map(String::from);
                         if let Some(url) = url {
                             let needs_render = match &ui.last_cover_image_render_info {
                                 Some((last_url, last_time)) => {
@@ -103,17 +108,17 @@ pub fn render_playback_window(
                 (metadata_rect, progress_bar_rect)
             };
 
-            render_playback_metadata(frame, state, ui, metadata_rect, track, playback);
+            render_playback_metadata(frame, state, ui, metadata_rect, item, playback);
 
             let progress = std::cmp::min(
                 player
                     .playback_progress()
                     .context("playback should exist")?,
-                track.duration,
+                duration,
             );
-            render_playback_progress_bar(frame, ui, progress, track, progress_bar_rect);
+            render_playback_progress_bar(frame, ui, progress, duration, progress_bar_rect);
         } else {
-            tracing::warn!("Got a non-track playable item: {:?}", playback.item);
+            tracing::warn!("Got a playback without a playable item: {playback:?}");
         }
     } else {
         // Previously rendered image can result in weird rendering text,
@@ -143,9 +148,24 @@ fn render_playback_metadata(
     state: &SharedState,
     ui: &UIStateGuard,
     rect: Rect,
-    track: &rspotify_model::FullTrack,
+    item: &rspotify_model::PlayableItem,
     playback: &rspotify_model::CurrentPlaybackContext,
 ) {
+    // a podcast episode is rendered with its show's publisher and name
+    // in place of a track's artists and album
+    let (name, artists, album) = match item {
+        rspotify_model::PlayableItem::Track(track) => (
+            &track.name,
+            crate::utils::map_join(&track.artists, |a| &a.name, ", "),
+            &track.album.name,
+        ),
+        rspotify_model::PlayableItem::Episode(episode) => (
+            &episode.name,
+            episode.show.publisher.clone(),
+            &episode.show.name,
+        ),
+    };
+
     let playback_info = vec![
         Span::styled(
             format!(
@@ -155,13 +175,13 @@ fn render_playback_metadata(
                 } else {
                     &state.app_config.play_icon
                 },
-                track.name,
-                crate::utils::map_join(&track.artists, |a| &a.name, ", ")
+                name,
+                artists
             ),
             ui.theme.playback_track(),
         )
         .into(),
-        Span::styled(track.album.name.to_string(), ui.theme.playback_album()).into(),
+        Span::styled(album.to_string(), ui.theme.playback_album()).into(),
         Span::styled(
             format!(
                 "repeat: {} | shuffle: {} | volume: {}% | device: {}",
@@ -186,18 +206,18 @@ fn render_playback_progress_bar(
     frame: &mut Frame,
     ui: &mut UIStateGuard,
     progress: std::time::Duration,
-    track: &rspotify_model::FullTrack,
+    duration: std::time::Duration,
     rect: Rect,
 ) {
     let progress_bar = Gauge::default()
         .block(Block::default())
         .gauge_style(ui.theme.playback_progress_bar())
-        .ratio(progress.as_secs_f64() / track.duration.as_secs_f64())
+        .ratio(progress.as_secs_f64() / duration.as_secs_f64())
         .label(Span::styled(
             format!(
                 "{}/{}",
                 crate::utils::format_duration(progress),
-                crate::utils::format_duration(track.duration),
+                crate::utils::format_duration(duration),
             ),
             Style::default().add_modifier(Modifier::BOLD),
         ));
@@ -41,6 +41,160 @@ pub fn render_popup(
                 frame.render_widget(widget, chunks[1]);
                 (chunks[0], true)
             }
+            PopupState::PlaylistEditor(editor) => {
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(0), Constraint::Length(6)].as_ref())
+                    .split(rect);
+
+                // an unknown value is displayed as `[?]`
+                let checkbox = |checked: Option<bool>| match checked {
+                    Some(true) => "[x]",
+                    Some(false) => "[ ]",
+                    None => "[?]",
+                };
+                let fields = [
+                    (PlaylistEditorField::Name, format!("Name: {}", editor.name)),
+                    (
+                        PlaylistEditorField::Description,
+                        format!(
+                            "Description: {}",
+                            editor.description.as_deref().unwrap_or_default()
+                        ),
+                    ),
+                    (
+                        PlaylistEditorField::Public,
+                        format!("Public: {}", checkbox(editor.public)),
+                    ),
+                    (
+                        PlaylistEditorField::Collaborative,
+                        format!("Collaborative: {}", checkbox(Some(editor.collaborative))),
+                    ),
+                ];
+                let lines = fields
+                    .into_iter()
+                    .map(|(field, text)| {
+                        Spans::from(Span::styled(
+                            text,
+                            if field == editor.focus {
+                                ui.theme.selection_style(true)
+                            } else {
+                                Style::default()
+                            },
+                        ))
+                    })
+                    .collect::<Vec<_>>();
+
+                let title = if editor.playlist.is_some() {
+                    "Edit Playlist"
+                } else if !editor.tracks.is_empty() {
+                    "Save as Playlist"
+                } else {
+                    "New Playlist"
+                };
+                let widget = Paragraph::new(lines).block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(ui.theme.block_title_with_style(title)),
+                );
+                frame.render_widget(widget, chunks[1]);
+                (chunks[0], false)
+            }
+            PopupState::RecommendationsBuilder => {
+                let builder = &ui.recommendations_builder;
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([
+                        Constraint::Min(0),
+                        Constraint::Length(4 + TunableAttribute::ALL.len() as u16),
+                    ])
+                    .split(rect);
+
+                let mut fields = vec![
+                    (
+                        RecommendationsBuilderField::Seeds,
+                        format!(
+                            "Seeds: {}",
+                            crate::utils::map_join(&builder.seeds, |s| s.name(), ", ")
+                        ),
+                    ),
+                    (
+                        RecommendationsBuilderField::Genres,
+                        format!("Genres: {}", builder.genres),
+                    ),
+                ];
+                fields.extend(TunableAttribute::ALL.into_iter().map(|attribute| {
+                    (
+                        RecommendationsBuilderField::Attribute(attribute),
+                        format!("{attribute:?}: {}", builder.attribute_input(attribute)),
+                    )
+                }));
+                let lines = fields
+                    .into_iter()
+                    .map(|(field, text)| {
+                        Spans::from(Span::styled(
+                            text,
+                            if field == builder.focus {
+                                ui.theme.selection_style(true)
+                            } else {
+                                Style::default()
+                            },
+                        ))
+                    })
+                    .collect::<Vec<_>>();
+
+                let n_seeds = builder.seeds.len() + builder.genre_seeds().len();
+                let widget =
+                    Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(
+                        ui.theme.block_title_with_style(format!(
+                            "Recommendations ({n_seeds}/{MAX_RECOMMENDATION_SEEDS} seeds)"
+                        )),
+                    ));
+                frame.render_widget(widget, chunks[1]);
+                (chunks[0], false)
+            }
+            PopupState::PlaylistTrackPosition { position, .. } => {
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
+                    .split(rect);
+
+                let widget = Paragraph::new(format!("Position: {position}")).block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(ui.theme.block_title_with_style("Move Track")),
+                );
+                frame.render_widget(widget, chunks[1]);
+                (chunks[0], false)
+            }
+            PopupState::OpenLink { link } => {
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
+                    .split(rect);
+
+                let widget = Paragraph::new(format!("Link: {link}")).block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(ui.theme.block_title_with_style("Open Spotify URL or URI")),
+                );
+                frame.render_widget(widget, chunks[1]);
+                (chunks[0], false)
+            }
+            PopupState::Message(message) => {
+                let chunks = Layout::default()
+                    .direction(Direction::Vertical)
+                    .constraints([Constraint::Min(0), Constraint::Length(3)].as_ref())
+                    .split(rect);
+
+                let widget = Paragraph::new(message.as_str()).block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(ui.theme.block_title_with_style("Message")),
+                );
+                frame.render_widget(widget, chunks[1]);
+                (chunks[0], false)
+            }
             PopupState::CommandHelp { .. } => {
                 let chunks = Layout::default()
                     .direction(Direction::Vertical)
@@ -90,7 +244,7 @@ pub fn render_popup(
                 let data = state.data.read();
                 let playlists = match action {
                     PlaylistPopupAction::Browse => data.user_data.playlists.iter().collect(),
-                    PlaylistPopupAction::AddTrack(_) => data.user_data.modifiable_playlists(),
+                    PlaylistPopupAction::AddTracks(_) => data.user_data.modifiable_playlists(),
                 };
                 let items = playlists
                     .into_iter()
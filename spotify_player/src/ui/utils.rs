@@ -7,6 +7,28 @@ pub fn construct_list_widget<'a>(
     title: &str,
     is_active: bool,
     borders: Option<Borders>,
+) -> (List<'a>, usize) {
+    let items = items
+        .into_iter()
+        .map(|(s, is_active)| {
+            let style = if is_active {
+                theme.current_playing()
+            } else {
+                Style::default()
+            };
+            (s, style)
+        })
+        .collect();
+    construct_styled_list_widget(theme, items, title, is_active, borders)
+}
+
+/// constructs a generic list widget whose items have custom styles
+pub fn construct_styled_list_widget<'a>(
+    theme: &config::Theme,
+    items: Vec<(String, Style)>,
+    title: &str,
+    is_active: bool,
+    borders: Option<Borders>,
 ) -> (List<'a>, usize) {
     let n_items = items.len();
     let borders = borders.unwrap_or(Borders::ALL);
@@ -15,13 +37,7 @@ pub fn construct_list_widget<'a>(
         List::new(
             items
                 .into_iter()
-                .map(|(s, is_active)| {
-                    ListItem::new(s).style(if is_active {
-                        theme.current_playing()
-                    } else {
-                        Style::default()
-                    })
-                })
+                .map(|(s, style)| ListItem::new(s).style(style))
                 .collect::<Vec<_>>(),
         )
         .highlight_style(theme.selection_style(is_active))
@@ -1,8 +1,13 @@
 use std::borrow::Cow;
+use std::io::Write;
 
 use tui::widgets::*;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
+use crate::state::{
+    AlbumId, ArtistId, ContextId, Id, PlaylistId, ShowId, SpotifyLink, TrackId, UserId,
+};
+
 /// formats a time duration into a "{minutes}:{seconds}" format
 pub fn format_duration(duration: std::time::Duration) -> String {
     let secs = duration.as_secs();
@@ -68,6 +73,15 @@ pub fn get_track_album_image_url(track: &rspotify::model::FullTrack) -> Option<&
     }
 }
 
+#[cfg(feature = "image")]
+pub fn get_episode_image_url(episode: &rspotify::model::FullEpisode) -> Option<&str> {
+    episode
+        .images
+        .first()
+        .or_else(|| episode.show.images.first())
+        .map(|image| image.url.as_str())
+}
+
 pub fn parse_uri(uri: &str) -> Cow<str> {
     let parts = uri.split(':').collect::<Vec<_>>();
     // The below URI probably has a format of `spotify:user:{user_id}:{type}:{id}`,
@@ -80,3 +94,231 @@ pub fn parse_uri(uri: &str) -> Cow<str> {
         Cow::Borrowed(uri)
     }
 }
+
+/// parses a Spotify URL (`https://open.spotify.com/{type}/{id}`) or
+/// a Spotify URI (`spotify:{type}:{id}`) into the referred Spotify item
+pub fn parse_spotify_link(link: &str) -> anyhow::Result<SpotifyLink> {
+    let link = link.trim();
+    let uri = match link.split_once("open.spotify.com/") {
+        Some((_, path)) => {
+            // remove the URL's query and fragment, e.g. `?si=...`
+            let path = path.split(['?', '#']).next().unwrap_or_default();
+            let parts = path
+                .split('/')
+                .filter(|p| !p.is_empty())
+                // a localized URL has a path prefix like `intl-de/`
+                .skip_while(|p| p.starts_with("intl-"))
+                .collect::<Vec<_>>();
+            format!("spotify:{}", parts.join(":"))
+        }
+        None => link.to_string(),
+    };
+    let uri = parse_uri(&uri);
+
+    let parts = uri.split(':').collect::<Vec<_>>();
+    let item = match parts.as_slice() {
+        ["spotify", "track", _] => TrackId::from_uri(&uri).map(SpotifyLink::Track),
+        ["spotify", "album", _] => {
+            AlbumId::from_uri(&uri).map(|id| SpotifyLink::Context(ContextId::Album(id)))
+        }
+        ["spotify", "artist", _] => {
+            ArtistId::from_uri(&uri).map(|id| SpotifyLink::Context(ContextId::Artist(id)))
+        }
+        ["spotify", "playlist", _] => {
+            PlaylistId::from_uri(&uri).map(|id| SpotifyLink::Context(ContextId::Playlist(id)))
+        }
+        ["spotify", "show", _] => {
+            ShowId::from_uri(&uri).map(|id| SpotifyLink::Context(ContextId::Show(id)))
+        }
+        ["spotify", "user", _] => UserId::from_uri(&uri).map(SpotifyLink::User),
+        _ => anyhow::bail!("unsupported Spotify link {link}"),
+    };
+    item.map_err(|err| anyhow::anyhow!("invalid Spotify link {link}: {err}"))
+}
+
+/// formats a Spotify item's link using a format template, whose placeholders `{url}`, `{uri}`,
+/// `{name}` and `{artists}` are replaced by the item's corresponding values
+pub fn format_share_link(format: &str, uri: &str, name: &str, artists: &str) -> String {
+    let url = format!(
+        "https://open.spotify.com/{}",
+        uri.trim_start_matches("spotify:").replace(':', "/")
+    );
+    format_template(
+        format,
+        &[
+            ("url", &url),
+            ("uri", uri),
+            ("name", name),
+            ("artists", artists),
+        ],
+    )
+}
+
+/// formats a template by replacing its `{name}` placeholders with the corresponding values.
+/// The template is scanned in a single pass, so placeholders inside the values are kept as-is.
+pub fn format_template(template: &str, values: &[(&str, &str)]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let placeholder = rest.find('}').and_then(|end| {
+            let name = &rest[1..end];
+            values
+                .iter()
+                .find(|(n, _)| *n == name)
+                .map(|(_, value)| (end, value))
+        });
+        match placeholder {
+            Some((end, value)) => {
+                result.push_str(value);
+                rest = &rest[end + 1..];
+            }
+            // not a known placeholder, keep the brace
+            None => {
+                result.push('{');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// copies a text to the terminal's clipboard using the OSC 52 escape sequence,
+/// which is handled by the terminal emulator, so it also works over SSH
+pub fn copy_to_clipboard(text: &str) -> std::io::Result<()> {
+    let sequence = format!("\x1b]52;c;{}\x07", base64::encode(text));
+    // the sequence is written at once to not be interleaved with the UI's outputs
+    let mut stdout = std::io::stdout().lock();
+    stdout.write_all(sequence.as_bytes())?;
+    stdout.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_share_link, format_template, parse_spotify_link};
+    use crate::state::{ContextId, Id, SpotifyLink};
+
+    /// parses a Spotify link and returns the referred item's URI
+    fn link_uri(link: &str) -> String {
+        match parse_spotify_link(link).unwrap() {
+            SpotifyLink::Track(id) => id.uri(),
+            SpotifyLink::Context(id) => id.uri(),
+            SpotifyLink::User(id) => id.uri(),
+        }
+    }
+
+    #[test]
+    fn parse_spotify_uri() {
+        assert_eq!(
+            link_uri("spotify:track:4uLU6hMCjMI75M1A2tKUQC"),
+            "spotify:track:4uLU6hMCjMI75M1A2tKUQC"
+        );
+        assert_eq!(
+            link_uri("  spotify:album:4aawyAB9vmqN3uQ7FjRGTy\n"),
+            "spotify:album:4aawyAB9vmqN3uQ7FjRGTy"
+        );
+        assert!(matches!(
+            parse_spotify_link("spotify:artist:0gxyHStUsqpMadRV0Di1Qt").unwrap(),
+            SpotifyLink::Context(ContextId::Artist(_))
+        ));
+        assert!(matches!(
+            parse_spotify_link("spotify:show:5CfCWKI5pZ28U0uOzXkDHe").unwrap(),
+            SpotifyLink::Context(ContextId::Show(_))
+        ));
+        assert!(matches!(
+            parse_spotify_link("spotify:user:spotify").unwrap(),
+            SpotifyLink::User(_)
+        ));
+    }
+
+    #[test]
+    fn parse_user_playlist_uri() {
+        let link = parse_spotify_link("spotify:user:spotify:playlist:37i9dQZF1DXcBWIGoYBM5M");
+        assert!(matches!(
+            link.unwrap(),
+            SpotifyLink::Context(ContextId::Playlist(_))
+        ));
+        assert_eq!(
+            link_uri("spotify:user:spotify:playlist:37i9dQZF1DXcBWIGoYBM5M"),
+            "spotify:playlist:37i9dQZF1DXcBWIGoYBM5M"
+        );
+    }
+
+    #[test]
+    fn parse_spotify_url() {
+        assert_eq!(
+            link_uri("https://open.spotify.com/track/4uLU6hMCjMI75M1A2tKUQC"),
+            "spotify:track:4uLU6hMCjMI75M1A2tKUQC"
+        );
+        assert_eq!(
+            link_uri("https://open.spotify.com/album/4aawyAB9vmqN3uQ7FjRGTy?si=abc123&nd=1"),
+            "spotify:album:4aawyAB9vmqN3uQ7FjRGTy"
+        );
+        assert_eq!(
+            link_uri("https://open.spotify.com/intl-de/track/4uLU6hMCjMI75M1A2tKUQC?si=abc"),
+            "spotify:track:4uLU6hMCjMI75M1A2tKUQC"
+        );
+        assert_eq!(
+            link_uri("open.spotify.com/playlist/37i9dQZF1DXcBWIGoYBM5M#fragment"),
+            "spotify:playlist:37i9dQZF1DXcBWIGoYBM5M"
+        );
+        assert_eq!(
+            link_uri("https://open.spotify.com/user/spotify/"),
+            "spotify:user:spotify"
+        );
+    }
+
+    #[test]
+    fn parse_invalid_spotify_link() {
+        for link in [
+            "",
+            "garbage",
+            "spotify:",
+            "spotify:track",
+            "spotify:episode:4uLU6hMCjMI75M1A2tKUQC",
+            "spotify:track:4uLU6hMCjMI75M1A2tKUQC:extra",
+            "spotify:track:not-a-valid-id!",
+            "https://open.spotify.com/",
+            "https://open.spotify.com/intl-de/",
+            "https://open.spotify.com/genre/rock",
+            "https://example.com/track/4uLU6hMCjMI75M1A2tKUQC",
+        ] {
+            assert!(
+                parse_spotify_link(link).is_err(),
+                "{link:?} should be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn template_placeholders() {
+        let values = [("track", "Song"), ("artists", "A, B")];
+        assert_eq!(
+            format_template("{track} by {artists}", &values),
+            "Song by A, B"
+        );
+        assert_eq!(
+            format_template("{unknown} {track}{", &values),
+            "{unknown} Song{"
+        );
+    }
+
+    #[test]
+    fn template_placeholders_in_values() {
+        let values = [("track", "{artists}"), ("artists", "A")];
+        assert_eq!(
+            format_template("{track} - {artists}", &values),
+            "{artists} - A"
+        );
+    }
+
+    #[test]
+    fn share_link() {
+        assert_eq!(
+            format_share_link("{name}: {url}", "spotify:track:abc", "{url}", ""),
+            "{url}: https://open.spotify.com/track/abc"
+        );
+    }
+}